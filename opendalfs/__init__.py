@@ -1,8 +1,51 @@
+from .batch import RetryBudget
+from .budget import get_memory_budget, set_memory_budget
+from .exceptions import (
+    ERROR_TRANSLATIONS,
+    CapabilityError,
+    FileChangedError,
+    KeyTooLongError,
+    ListingInterrupted,
+    PartialDeleteError,
+    register_error_translation,
+)
+from .file import WriteResult
+from .frames import to_arrow, to_pandas, to_polars
 from .fs import OpendalFileSystem
+from .health import HealthStatus, health
+from .metrics import OperationStats, ReadTiming, RuntimeStats
+from .pool import OperatorPool
+from .presign import SignedRequest
 from .registry import register_opendal_protocols, register_opendal_service
+from .sequence import Sequence
+from .uploader import UploadReport, Uploader
 
 __all__ = [
+    "ERROR_TRANSLATIONS",
+    "CapabilityError",
+    "FileChangedError",
+    "HealthStatus",
+    "KeyTooLongError",
+    "ListingInterrupted",
     "OpendalFileSystem",
+    "OperationStats",
+    "OperatorPool",
+    "PartialDeleteError",
+    "ReadTiming",
+    "RetryBudget",
+    "RuntimeStats",
+    "Sequence",
+    "SignedRequest",
+    "UploadReport",
+    "Uploader",
+    "WriteResult",
+    "get_memory_budget",
+    "health",
+    "register_error_translation",
     "register_opendal_protocols",
     "register_opendal_service",
+    "set_memory_budget",
+    "to_arrow",
+    "to_pandas",
+    "to_polars",
 ]
@@ -0,0 +1,94 @@
+from __future__ import annotations
+
+import asyncio
+import inspect
+import threading
+from collections.abc import Awaitable, Iterable
+from dataclasses import dataclass, field
+from typing import Any
+
+from fsspec.asyn import _get_batch_size
+
+ON_ERROR_POLICIES = ("raise", "return", "ignore")
+
+
+@dataclass
+class RetryBudget:
+    """Retries shared by all items of a batch.
+
+    Pass one as ``retry_budget=`` together with per-item ``retries=``: an
+    item only retries while the budget allows, so a few failing keys cannot
+    multiply a job's runtime. ``max_retries`` caps the retries taken in
+    total and ``max_added_latency`` the seconds spent backing off; ``None``
+    leaves that dimension open. ``retries`` and ``added_latency`` record what
+    was consumed.
+    """
+
+    max_retries: int | None = None
+    max_added_latency: float | None = None
+    retries: int = 0
+    added_latency: float = 0.0
+    _lock: threading.Lock = field(default_factory=threading.Lock, repr=False, compare=False)
+
+    def spend(self, delay: float) -> bool:
+        """Take one retry backing off ``delay`` seconds, if the budget allows"""
+        with self._lock:
+            if self.max_retries is not None and self.retries >= self.max_retries:
+                return False
+            if (
+                self.max_added_latency is not None
+                and self.added_latency + delay > self.max_added_latency
+            ):
+                return False
+            self.retries += 1
+            self.added_latency += delay
+            return True
+
+
+async def run_batch(
+    aws: Iterable[Awaitable[Any]],
+    on_error: str = "raise",
+    limit: int | None = None,
+) -> list[Any]:
+    """Run awaitables with bounded concurrency and an error policy.
+
+    ``on_error`` decides what a failing item does to the batch:
+
+    - ``"raise"``: cancel the remaining items and raise the first error.
+    - ``"return"``: keep going and put the exception in place of the result.
+    - ``"ignore"``: keep going and drop failed items from the output.
+
+    ``limit`` caps how many items run at once and defaults to fsspec's
+    ``gather_batch_size`` setting.
+    """
+    if on_error not in ON_ERROR_POLICIES:
+        raise ValueError(f"on_error must be one of {ON_ERROR_POLICIES}, got {on_error!r}")
+
+    pending = list(aws)
+    if limit is None:
+        limit = _get_batch_size()
+    semaphore = asyncio.Semaphore(max(1, limit))
+
+    async def run(aw: Awaitable[Any]) -> Any:
+        async with semaphore:
+            return await aw
+
+    tasks = [asyncio.ensure_future(run(aw)) for aw in pending]
+
+    if on_error == "raise":
+        try:
+            return list(await asyncio.gather(*tasks))
+        except BaseException:
+            for task in tasks:
+                task.cancel()
+            await asyncio.gather(*tasks, return_exceptions=True)
+            for aw in pending:
+                # Items cancelled before they started were never awaited.
+                if inspect.iscoroutine(aw) and inspect.getcoroutinestate(aw) == inspect.CORO_CREATED:
+                    aw.close()
+            raise
+
+    results = await asyncio.gather(*tasks, return_exceptions=True)
+    if on_error == "return":
+        return list(results)
+    return [result for result in results if not isinstance(result, Exception)]
@@ -0,0 +1,82 @@
+from __future__ import annotations
+
+import asyncio
+import contextlib
+import threading
+from collections.abc import AsyncIterator, Iterator
+
+
+def _wake(future: asyncio.Future[None]) -> None:
+    if not future.done():
+        future.set_result(None)
+
+
+class MemoryBudget:
+    """Cap on the bytes held by reads in flight, shared by all filesystems.
+
+    Each read reserves its size before fetching and waits while the budget
+    is exhausted, so hundreds of concurrent ``cat`` calls queue instead of
+    running the process out of memory. A read larger than the whole budget
+    is let through on its own rather than waiting forever. Waiters may sit
+    on different event loops or on threads doing blocking reads.
+    """
+
+    def __init__(self, limit: int) -> None:
+        if limit <= 0:
+            raise ValueError("memory budget must be positive")
+        self.limit = limit
+        self.used = 0
+        self._lock = threading.Lock()
+        self._released = threading.Condition(self._lock)
+        self._waiters: list[tuple[asyncio.AbstractEventLoop, asyncio.Future[None]]] = []
+
+    @contextlib.asynccontextmanager
+    async def reserve(self, nbytes: int) -> AsyncIterator[None]:
+        nbytes = min(nbytes, self.limit)
+        loop = asyncio.get_running_loop()
+        while True:
+            with self._lock:
+                if self.used + nbytes <= self.limit:
+                    self.used += nbytes
+                    break
+                future = loop.create_future()
+                self._waiters.append((loop, future))
+            await future
+        try:
+            yield
+        finally:
+            self._release(nbytes)
+
+    @contextlib.contextmanager
+    def reserve_blocking(self, nbytes: int) -> Iterator[None]:
+        nbytes = min(nbytes, self.limit)
+        with self._released:
+            self._released.wait_for(lambda: self.used + nbytes <= self.limit)
+            self.used += nbytes
+        try:
+            yield
+        finally:
+            self._release(nbytes)
+
+    def _release(self, nbytes: int) -> None:
+        with self._released:
+            self.used -= nbytes
+            waiters, self._waiters = self._waiters, []
+            self._released.notify_all()
+        for loop, future in waiters:
+            if not loop.is_closed():
+                loop.call_soon_threadsafe(_wake, future)
+
+
+_budget: MemoryBudget | None = None
+
+
+def set_memory_budget(limit: int | None) -> MemoryBudget | None:
+    """Limit the bytes of concurrent reads across the process; ``None`` lifts it"""
+    global _budget
+    _budget = MemoryBudget(limit) if limit is not None else None
+    return _budget
+
+
+def get_memory_budget() -> MemoryBudget | None:
+    return _budget
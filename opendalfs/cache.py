@@ -0,0 +1,79 @@
+from __future__ import annotations
+
+import contextlib
+import hashlib
+import os
+import tempfile
+
+
+class DiskCache:
+    """Whole-object cache on local disk, keyed by path and ETag.
+
+    An ETag identifies one immutable version of an object, so entries never
+    need invalidation: a new version simply gets a new key. Entries are
+    written to a temporary file and moved into place with ``os.replace``,
+    which lets several processes share one directory safely.
+
+    With ``max_size``, the least recently used entries are removed after a
+    write takes the cache above that many bytes; objects larger than the
+    limit are not cached at all.
+    """
+
+    def __init__(self, directory: str | os.PathLike[str], max_size: int | None = None) -> None:
+        self.directory = os.fspath(directory)
+        self.max_size = max_size
+        os.makedirs(self.directory, exist_ok=True)
+
+    def _entry_path(self, namespace: str, path: str, etag: str) -> str:
+        key = "\0".join((namespace, path, etag)).encode()
+        return os.path.join(self.directory, hashlib.sha256(key).hexdigest())
+
+    def get(self, namespace: str, path: str, etag: str) -> bytes | None:
+        entry = self._entry_path(namespace, path, etag)
+        try:
+            with open(entry, "rb") as f:
+                data = f.read()
+        except FileNotFoundError:
+            return None
+        # The modification time doubles as the last use for eviction.
+        with contextlib.suppress(FileNotFoundError):
+            os.utime(entry)
+        return data
+
+    def put(self, namespace: str, path: str, etag: str, data: bytes) -> None:
+        if self.max_size is not None and len(data) > self.max_size:
+            return
+        fd, tmp = tempfile.mkstemp(dir=self.directory, prefix=".tmp-")
+        try:
+            with os.fdopen(fd, "wb") as f:
+                f.write(data)
+            os.replace(tmp, self._entry_path(namespace, path, etag))
+        except BaseException:
+            try:
+                os.unlink(tmp)
+            except FileNotFoundError:
+                pass
+            raise
+        self._evict()
+
+    def _evict(self) -> None:
+        """Remove the least recently used entries until the cache fits"""
+        if self.max_size is None:
+            return
+        entries = []
+        with os.scandir(self.directory) as it:
+            for entry in it:
+                if entry.name.startswith(".tmp-"):
+                    continue
+                try:
+                    stat = entry.stat()
+                except FileNotFoundError:
+                    continue
+                entries.append((stat.st_mtime_ns, stat.st_size, entry.path))
+        total = sum(size for _, size, _ in entries)
+        for _, size, entry_path in sorted(entries):
+            if total <= self.max_size:
+                break
+            with contextlib.suppress(FileNotFoundError):
+                os.unlink(entry_path)
+            total -= size
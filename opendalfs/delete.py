@@ -0,0 +1,91 @@
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from typing import Any
+
+# Keys deleted per group; every key in a group is still its own request.
+DEFAULT_DELETE_GROUP_SIZE = 1000
+
+# Plans removing more entries than this get a lifecycle rule suggestion.
+LIFECYCLE_SUGGESTION_THRESHOLD = 100_000
+
+# S3 limits lifecycle rule IDs to 255 characters.
+_MAX_RULE_ID_LENGTH = 255
+
+
+@dataclass
+class DeletePlan:
+    """Keys and prefixes to delete, grouped for execution.
+
+    ``prefixes`` maps each prefix to the number of entries listed under it,
+    or ``None`` when the plan was made without counting them. Whole prefixes
+    are removed with OpenDAL's ``remove_all``, which lists and deletes with
+    the backend's batch API where it has one. The Python bindings have no
+    batch delete for arbitrary keys, so individual keys are deleted with one
+    request each, ``batch_size`` keys at a time with bounded concurrency.
+    """
+
+    keys: list[str] = field(default_factory=list)
+    prefixes: dict[str, int | None] = field(default_factory=dict)
+    batch_size: int = DEFAULT_DELETE_GROUP_SIZE
+
+    @property
+    def total(self) -> int:
+        """Number of entries the plan removes, uncounted prefixes aside"""
+        return len(self.keys) + sum(count or 0 for count in self.prefixes.values())
+
+    @property
+    def groups(self) -> list[list[str]]:
+        """The individual keys in groups of ``batch_size``"""
+        return [
+            self.keys[i : i + self.batch_size]
+            for i in range(0, len(self.keys), self.batch_size)
+        ]
+
+    @property
+    def requests(self) -> int:
+        """Delete calls executing the plan sends, one per prefix and key"""
+        return len(self.prefixes) + len(self.keys)
+
+    def lifecycle_suggestion(
+        self, threshold: int = LIFECYCLE_SUGGESTION_THRESHOLD
+    ) -> dict[str, Any] | None:
+        """S3 lifecycle configuration expiring the plan's prefixes, if worth it
+
+        Past ``threshold`` entries, an expiration rule lets the service delete
+        in the background without a request per key. Only whole prefixes can
+        be expressed as rules, so plans of individual keys get ``None``.
+        """
+        if self.total <= threshold or not self.prefixes:
+            return None
+        return {
+            "Rules": [
+                {
+                    "ID": f"opendalfs-expire-{prefix}"[:_MAX_RULE_ID_LENGTH],
+                    "Filter": {"Prefix": prefix},
+                    "Status": "Enabled",
+                    "Expiration": {"Days": 1},
+                }
+                for prefix in sorted(self.prefixes)
+            ]
+        }
+
+
+@dataclass
+class DeleteReport:
+    """Outcome of executing a ``DeletePlan``
+
+    ``suggestion`` holds the plan's lifecycle rule suggestion, when ``rm``
+    was given a ``lifecycle_threshold`` the delete went past.
+    """
+
+    deleted: int = 0
+    failed: dict[str, BaseException] = field(default_factory=dict)
+    requests: int = 0
+    retries: int = 0
+    elapsed: float = 0.0
+    suggestion: dict[str, Any] | None = None
+
+    @property
+    def items_per_second(self) -> float:
+        return self.deleted / self.elapsed if self.elapsed else 0.0
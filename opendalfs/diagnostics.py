@@ -0,0 +1,114 @@
+from __future__ import annotations
+
+import asyncio
+import ssl
+import time
+import uuid
+from collections.abc import Awaitable, Callable
+from dataclasses import asdict, dataclass, field
+from typing import TYPE_CHECKING, Any
+from urllib.parse import urlsplit
+
+from opendal.exceptions import NotFound, PermissionDenied
+
+if TYPE_CHECKING:
+    from .fs import OpendalFileSystem
+
+
+@dataclass
+class Check:
+    """Outcome of one diagnostic step; ``ok`` is ``None`` when skipped"""
+
+    name: str
+    ok: bool | None
+    detail: str
+    elapsed: float = 0.0
+
+
+@dataclass
+class DiagnosticReport:
+    checks: list[Check] = field(default_factory=list)
+
+    @property
+    def ok(self) -> bool:
+        return all(check.ok is not False for check in self.checks)
+
+    def to_dict(self) -> dict[str, Any]:
+        return {"ok": self.ok, "checks": [asdict(check) for check in self.checks]}
+
+
+async def _timed(
+    name: str, probe: Callable[[], Awaitable[str]], timeout: float
+) -> Check:
+    start = time.perf_counter()
+    try:
+        detail = await asyncio.wait_for(probe(), timeout)
+    except asyncio.TimeoutError:
+        return Check(name, False, f"timed out after {timeout}s", time.perf_counter() - start)
+    except Exception as err:
+        return Check(name, False, f"{type(err).__name__}: {err}", time.perf_counter() - start)
+    return Check(name, True, detail, time.perf_counter() - start)
+
+
+async def _authenticate(fs: OpendalFileSystem) -> str:
+    """HEAD a key that cannot exist; a ``NotFound`` proves the signature is accepted"""
+    probe = f".opendalfs-diagnose-{uuid.uuid4().hex}"
+    try:
+        await fs.async_fs.stat(probe)
+    except NotFound:
+        return "credentials accepted"
+    except PermissionDenied as err:
+        raise PermissionError(f"credentials rejected: {err}") from err
+    return "credentials accepted"
+
+
+async def _list_root(fs: OpendalFileSystem) -> str:
+    """Check that the bucket or container exists and can be listed"""
+    if not await fs._bucket_exists():
+        raise FileNotFoundError("bucket or container does not exist")
+    return "listing allowed"
+
+
+async def run_diagnostics(fs: OpendalFileSystem, timeout: float = 10.0) -> DiagnosticReport:
+    """Run cheap connectivity, auth and permission checks against a filesystem"""
+    report = DiagnosticReport()
+    endpoint = fs.storage_options.get("endpoint")
+    parsed = urlsplit(endpoint) if endpoint else None
+    host = parsed.hostname if parsed else None
+
+    if parsed is None or host is None:
+        report.checks.append(Check("dns", None, "no endpoint configured"))
+        report.checks.append(Check("tls", None, "no endpoint configured"))
+    else:
+        port = parsed.port or (443 if parsed.scheme == "https" else 80)
+
+        async def resolve() -> str:
+            infos = await asyncio.get_running_loop().getaddrinfo(host, port)
+            return ", ".join(sorted({info[4][0] for info in infos}))
+
+        dns = await _timed("dns", resolve, timeout)
+        report.checks.append(dns)
+        if not dns.ok:
+            report.checks.append(Check("tls", None, "skipped after DNS failure"))
+            report.checks.append(Check("auth", None, "skipped after DNS failure"))
+            report.checks.append(Check("list", None, "skipped after DNS failure"))
+            return report
+
+        if parsed.scheme == "https":
+
+            async def handshake() -> str:
+                _, writer = await asyncio.open_connection(
+                    host, port, ssl=ssl.create_default_context(), server_hostname=host
+                )
+                version = writer.get_extra_info("ssl_object").version()
+                writer.close()
+                await writer.wait_closed()
+                return f"negotiated {version}"
+
+            report.checks.append(await _timed("tls", handshake, timeout))
+        else:
+            report.checks.append(Check("tls", None, "plain HTTP endpoint"))
+
+    report.checks.append(await _timed("auth", lambda: _authenticate(fs), timeout))
+    report.checks.append(await _timed("list", lambda: _list_root(fs), timeout))
+    return report
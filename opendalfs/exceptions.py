@@ -0,0 +1,124 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import opendal.exceptions
+
+if TYPE_CHECKING:
+    from .delete import DeleteReport
+
+
+class CapabilityError(NotImplementedError):
+    """The backend lacks a capability an operation needs
+
+    ``alternative`` describes how the filesystem emulates it when built with
+    ``emulate_missing=True``, or is ``None`` when it cannot be emulated.
+    """
+
+    def __init__(self, scheme: str, capability: str, alternative: str | None = None) -> None:
+        message = f"{scheme} does not support {capability}"
+        if alternative is not None:
+            message += f"; emulate_missing=True would fall back to {alternative}"
+        super().__init__(message)
+        self.scheme = scheme
+        self.capability = capability
+        self.alternative = alternative
+
+
+class FileChangedError(OSError):
+    """An object was replaced while it was being read
+
+    Raised instead of returning bytes that mix two versions of the object.
+    """
+
+    def __init__(self, path: str, expected: str | None, actual: str | None) -> None:
+        super().__init__(f"{path} changed while reading: ETag {expected} is now {actual}")
+        self.path = path
+        self.expected = expected
+        self.actual = actual
+
+
+class KeyTooLongError(ValueError):
+    """An object key exceeds the backend's length limit
+
+    Raised before any data is sent, rather than after a partial upload fails
+    with an opaque error from the service.
+    """
+
+    def __init__(self, path: str, length: int, limit: int) -> None:
+        super().__init__(f"key is {length} bytes, over the {limit} byte limit: {path[:64]}...")
+        self.path = path
+        self.length = length
+        self.limit = limit
+
+
+class ListingInterrupted(OSError):
+    """A listing failed after some entries were received
+
+    ``entries`` holds what was listed before the failure. Listing again with
+    ``start_after=err.start_after`` continues from there instead of
+    restarting; the original error is the ``__cause__``.
+    """
+
+    def __init__(self, path: str, entries: list[str], start_after: str) -> None:
+        super().__init__(f"listing {path!r} failed after {len(entries)} entries")
+        self.path = path
+        self.entries = entries
+        self.start_after = start_after
+
+
+class PartialDeleteError(OSError):
+    """Some keys of a bulk delete could not be removed
+
+    The others were deleted regardless; ``report`` lists the failures by key
+    with their errors.
+    """
+
+    def __init__(self, report: DeleteReport) -> None:
+        failed = ", ".join(list(report.failed)[:5])
+        super().__init__(
+            f"{len(report.failed)} deletes failed ({failed}), {report.deleted} succeeded"
+        )
+        self.report = report
+
+
+def _opendal_error(name: str) -> type[Exception] | None:
+    return getattr(opendal.exceptions, name, None)
+
+
+# OpenDAL error class -> exception raised to callers. The filesystem handles
+# some kinds itself (e.g. NotFound in ``exists``); those are translated where
+# they leave the filesystem, all others as soon as an operator call fails.
+ERROR_TRANSLATIONS: dict[type[Exception], type[Exception]] = {
+    error: target
+    for name, target in (
+        ("NotFound", FileNotFoundError),
+        ("PermissionDenied", PermissionError),
+        ("AlreadyExists", FileExistsError),
+        ("IsADirectory", IsADirectoryError),
+        ("NotADirectory", NotADirectoryError),
+    )
+    if (error := _opendal_error(name)) is not None
+}
+_DEFAULT_TRANSLATIONS = dict(ERROR_TRANSLATIONS)
+
+
+def register_error_translation(error: type[Exception], exception: type[Exception]) -> None:
+    """Raise ``exception`` wherever OpenDAL raises ``error``
+
+    Replacing a default translation requires a subclass of the default, so
+    that fsspec callers catching e.g. ``FileNotFoundError`` keep working.
+    """
+    default = _DEFAULT_TRANSLATIONS.get(error)
+    if default is not None and not issubclass(exception, default):
+        raise TypeError(f"{error.__name__} must translate to a subclass of {default.__name__}")
+    ERROR_TRANSLATIONS[error] = exception
+
+
+def translate_error(err: BaseException, path: str | None = None) -> BaseException:
+    """Return the exception to raise for ``err``, or ``err`` if it has no mapping"""
+    for cls in type(err).__mro__:
+        target = ERROR_TRANSLATIONS.get(cls)
+        if target is not None:
+            return target(path if path is not None else str(err))
+    return err
@@ -1,20 +1,51 @@
+import asyncio
+import concurrent.futures
+import hashlib
+import io
 import logging
+from dataclasses import dataclass
 
 from fsspec.asyn import AbstractAsyncStreamedFile
 from fsspec.spec import AbstractBufferedFile
 from opendal import AsyncFile as OpendalAsyncFile
 from opendal import File as OpendalFile
-from opendal.exceptions import NotFound
+from opendal.exceptions import ConditionNotMatch, NotFound
 
 logger = logging.getLogger("opendalfs")
 
 
+@dataclass(frozen=True)
+class WriteResult:
+    """What a writer opened with ``checksum`` committed.
+
+    ``size`` is the size of the object after close; ``checksum`` is the hex
+    digest of the bytes written through this file, which for appends is not
+    the whole object.
+    """
+
+    path: str
+    size: int
+    etag: str | None
+    checksum: str
+
+
 class OpendalBufferedFile(AbstractBufferedFile):
-    """Buffered file implementation for OpenDAL"""
+    """Buffered file implementation for OpenDAL
+
+    Once ``close()`` returns, the object is committed in full on every
+    backend; if it raises, object stores keep the previous version. Bytes
+    written before that are only durable through ``fsync()`` on backends
+    where ``fs.durable_on_flush`` is true.
+
+    With ``readahead``, a read continuing where the previous one ended also
+    starts fetching the next ``readahead`` bytes in the background, so a
+    sequential scan overlaps downloading with processing.
+    """
 
     _opendal_writer: OpendalFile | None
     _append_via_write: bool
     _initiated: bool
+    _mmap_view: memoryview | None = None
 
     def __init__(
         self,
@@ -26,8 +57,14 @@ class OpendalBufferedFile(AbstractBufferedFile):
         cache_type="readahead",
         cache_options=None,
         size=None,
+        details=None,
+        check_exists=True,
+        checksum=None,
+        readahead=None,
         **kwargs,
     ):
+        if size is None and details is not None:
+            size = details["size"]
         super().__init__(
             fs,
             path,
@@ -40,26 +77,105 @@ class OpendalBufferedFile(AbstractBufferedFile):
             **kwargs,
         )
 
+        # Reuse metadata the filesystem already has instead of another stat.
+        if details is not None:
+            self._details = details
+
         self._opendal_writer = None
         self._append_via_write = False
         self._initiated = False
+        # Without the check, exclusive and append modes trust the caller.
+        self._check_exists = check_exists
+        # Range reads are pinned to the version seen at open, so a file that
+        # is overwritten mid-read raises FileChangedError instead of mixing.
+        # Only metadata already at hand is used: opening with size= and no
+        # details sends no HEAD and leaves reads unpinned.
+        known = getattr(self, "_details", None)
+        self._if_match = fs._pinned_etag(known) if mode == "rb" and known else None
+        # Hashing as bytes are written spares ingestion code a re-read.
+        self._digest = hashlib.new(checksum) if checksum and mode != "rb" else None
+        self.result: WriteResult | None = None
+        self.readahead = readahead if mode == "rb" and not fs.use_mmap else None
+        self._ahead: tuple[int, int, concurrent.futures.Future[bytes]] | None = None
+        self._last_end = 0
 
         if mode == "ab":
             # Match python semantics: append writes start from end-of-file.
-            try:
-                self.loc = self.details["size"]
-            except FileNotFoundError:
+            if details is None and not check_exists:
                 self.loc = 0
+            else:
+                try:
+                    self.loc = self.details["size"]
+                except FileNotFoundError:
+                    self.loc = 0
 
-    def _fetch_range(self, start: int, end: int):
+    def __len__(self) -> int:
+        return self.size
+
+    @property
+    def etag(self) -> str | None:
+        """ETag of the object as seen when the file was opened"""
+        return self.details.get("etag")
+
+    def write(self, data) -> int:
+        written = super().write(data)
+        if self._digest is not None:
+            self._digest.update(data)
+        return written
+
+    def _fetch_range(self, start: int, end: int) -> bytes | memoryview:
         """Download data between start and end"""
         if start >= end:
             return b""
 
-        length = end - start
-        return self.fs.operator.read(self.path, offset=start, size=length)
+        if self.fs.use_mmap:
+            if self._mmap_view is None:
+                self._mmap_view = self.fs._mmap_view(self.path)
+            return self._mmap_view[start:end]
+
+        if self.readahead:
+            return self._fetch_ahead(start, end)
+        return self._read(start, end)
 
-    def _upload_chunk(self, final: bool = False):
+    def _read(self, start: int, end: int) -> bytes:
+        if start >= end:
+            return b""
+        return self.fs._read_blocking(
+            self.path, offset=start, size=end - start, if_match=self._if_match
+        )
+
+    def _fetch_ahead(self, start: int, end: int) -> bytes:
+        """Serve a range from the background fetch where it covers it"""
+        ahead, self._ahead = self._ahead, None
+        data = None
+        if ahead is not None:
+            ahead_start, ahead_end, future = ahead
+            if ahead_start <= start < ahead_end:
+                buffered = future.result()
+                data = buffered[start - ahead_start : end - ahead_start]
+                data += self._read(ahead_end, end)
+                if end < ahead_end:
+                    # Still ahead of the reader; it serves the next reads too.
+                    self._ahead = ahead
+            else:
+                future.cancel()
+        if data is None:
+            data = self._read(start, end)
+
+        sequential = start == self._last_end
+        self._last_end = end
+        if self._ahead is None and sequential and end < self.size:
+            ahead_end = min(end + self.readahead, self.size)
+            future = asyncio.run_coroutine_threadsafe(
+                self.fs._read(
+                    self.path, offset=end, size=ahead_end - end, if_match=self._if_match
+                ),
+                self.fs.loop,
+            )
+            self._ahead = (end, ahead_end, future)
+        return data
+
+    def _upload_chunk(self, final: bool = False) -> bool | None:
         """Upload partial chunk of data"""
         if not self._initiated:
             raise RuntimeError("Upload has not been initiated")
@@ -71,7 +187,9 @@ class OpendalBufferedFile(AbstractBufferedFile):
             if not final:
                 return False
             if self.mode == "ab" and self._append_via_write:
-                if not self.fs.operator.exists(self.path):
+                if not self._check_exists:
+                    self.fs.operator.write(self.path, b"", append=True)
+                elif not self.fs.operator.exists(self.path):
                     self.fs.operator.write(self.path, b"")
                 return None
             self._commit_upload()
@@ -83,6 +201,16 @@ class OpendalBufferedFile(AbstractBufferedFile):
             return None
 
         if self._opendal_writer is None:
+            # Below the threshold the whole object goes out as one PUT.
+            if len(chunk) < self.fs.multipart_threshold:
+                if not final:
+                    return False
+                options = self.fs._create_options() if self.mode == "xb" else {}
+                try:
+                    self.fs.operator.write(self.path, chunk, **options)
+                except ConditionNotMatch as err:
+                    raise FileExistsError(self.path) from err
+                return None
             self._opendal_writer = self.fs.operator.open(self.path, "wb")
 
         if chunk:
@@ -92,12 +220,38 @@ class OpendalBufferedFile(AbstractBufferedFile):
             self._commit_upload()
         return None
 
+    def fsync(self) -> None:
+        """Make the bytes written so far durable, where the backend allows it
+
+        Object stores commit an object only when ``close()`` returns, so this
+        raises ``io.UnsupportedOperation`` on them instead of promising what
+        they cannot do.
+        """
+        if self.closed:
+            raise ValueError("I/O operation on closed file")
+        if not self.writable():
+            raise io.UnsupportedOperation("not open for writing")
+        if not self.fs.durable_on_flush:
+            raise io.UnsupportedOperation(f"{self.fs.scheme} commits written data only on close()")
+
+        if self.offset is None:
+            self.offset = 0
+            self._initiate_upload()
+        if self._opendal_writer is None and not self._append_via_write:
+            # Skip the single-PUT path: the data has to leave the buffer now.
+            self._opendal_writer = self.fs.operator.open(self.path, "wb")
+        if self._upload_chunk() is not False:
+            self.offset += self.buffer.seek(0, 2)
+            self.buffer = io.BytesIO()
+        if self._opendal_writer is not None:
+            self._opendal_writer.flush()
+
     def _initiate_upload(self) -> None:
         """Prepare for uploading"""
         if self._initiated:
             return
 
-        if self.mode == "xb" and self.fs.operator.exists(self.path):
+        if self.mode == "xb" and self._check_exists and self.fs.operator.exists(self.path):
             raise FileExistsError(self.path)
 
         if self.mode == "ab":
@@ -108,6 +262,7 @@ class OpendalBufferedFile(AbstractBufferedFile):
                 self.offset = self.loc
             else:
                 # Fallback: emulate append by rewriting the full object.
+                self.fs._emulate("write_can_append", "rewriting the object on every append")
                 try:
                     existing = self.fs.operator.read(self.path)
                 except (FileNotFoundError, NotFound):
@@ -126,30 +281,49 @@ class OpendalBufferedFile(AbstractBufferedFile):
 
         if self._opendal_writer is None:
             # Ensure empty files are created on close.
-            self.fs.operator.write(self.path, b"")
+            options = self.fs._create_options() if self.mode == "xb" else {}
+            try:
+                self.fs.operator.write(self.path, b"", **options)
+            except ConditionNotMatch as err:
+                raise FileExistsError(self.path) from err
             return
 
         self._opendal_writer.flush()
         self._opendal_writer.close()
         self._opendal_writer = None
 
-    def close(self):
-        """Ensure data is written before closing"""
+    def close(self) -> WriteResult | None:
+        """Ensure data is written before closing
+
+        Returns a ``WriteResult`` when the file was opened with ``checksum``,
+        also kept as ``result``.
+        """
         if self.closed:
-            return
+            return self.result
 
         try:
             super().close()
         finally:
+            self._mmap_view = None
+            if self._ahead is not None:
+                self._ahead[2].cancel()
+                self._ahead = None
             if self._opendal_writer is not None:
                 try:
                     self._opendal_writer.close()
                 finally:
                     self._opendal_writer = None
+        if self._digest is not None:
+            etag = getattr(self.fs.operator.stat(self.path), "etag", None)
+            self.result = WriteResult(self.path, self.loc, etag or None, self._digest.hexdigest())
+        return self.result
 
 
 class OpendalAsyncBufferedFile(AbstractAsyncStreamedFile):
-    """Async buffered file implementation for OpenDAL."""
+    """Async buffered file implementation for OpenDAL.
+
+    Durability follows ``OpendalBufferedFile``.
+    """
 
     _opendal_writer: OpendalAsyncFile | None
     _append_via_write: bool
@@ -166,10 +340,15 @@ class OpendalAsyncBufferedFile(AbstractAsyncStreamedFile):
         cache_type="readahead",
         cache_options=None,
         size=None,
+        details=None,
+        check_exists=True,
+        checksum=None,
         **kwargs,
     ):
         self._exclusive_create = mode == "xb"
         normalized_mode = "wb" if self._exclusive_create else mode
+        if size is None and details is not None:
+            size = details["size"]
         super().__init__(
             fs,
             path,
@@ -182,18 +361,41 @@ class OpendalAsyncBufferedFile(AbstractAsyncStreamedFile):
             **kwargs,
         )
 
+        # The lazy ``details`` property would stat synchronously; keep ours.
+        if details is not None:
+            self._details = details
+
         self._opendal_writer = None
         self._append_via_write = False
         self._initiated = False
-
-    async def _fetch_range(self, start: int, end: int):
+        self._check_exists = check_exists
+        self._if_match = None
+        if mode == "rb" and details is not None:
+            self._if_match = fs._pinned_etag(details)
+        self._digest = hashlib.new(checksum) if checksum and mode != "rb" else None
+        self.result: WriteResult | None = None
+
+    def __len__(self) -> int:
+        return self.size
+
+    @property
+    def etag(self) -> str | None:
+        return self.details.get("etag")
+
+    async def write(self, data) -> int:
+        written = await super().write(data)
+        if self._digest is not None:
+            self._digest.update(data)
+        return written
+
+    async def _fetch_range(self, start: int, end: int) -> bytes:
         if start >= end:
             return b""
 
         length = end - start
-        return await self.fs.async_fs.read(self.path, offset=start, size=length)
+        return await self.fs._read(self.path, offset=start, size=length, if_match=self._if_match)
 
-    async def _upload_chunk(self, final: bool = False):
+    async def _upload_chunk(self, final: bool = False) -> bool | None:
         if not self._initiated:
             raise RuntimeError("Upload has not been initiated")
 
@@ -204,7 +406,9 @@ class OpendalAsyncBufferedFile(AbstractAsyncStreamedFile):
             if not final:
                 return False
             if self.mode == "ab" and self._append_via_write:
-                if not await self.fs.async_fs.exists(self.path):
+                if not self._check_exists:
+                    await self.fs.async_fs.write(self.path, b"", append=True)
+                elif not await self.fs.async_fs.exists(self.path):
                     await self.fs.async_fs.write(self.path, b"")
                 return None
             await self._commit_upload()
@@ -215,6 +419,15 @@ class OpendalAsyncBufferedFile(AbstractAsyncStreamedFile):
             return None
 
         if self._opendal_writer is None:
+            if len(chunk) < self.fs.multipart_threshold:
+                if not final:
+                    return False
+                options = self.fs._create_options() if self._exclusive_create else {}
+                try:
+                    await self.fs.async_fs.write(self.path, chunk, **options)
+                except ConditionNotMatch as err:
+                    raise FileExistsError(self.path) from err
+                return None
             self._opendal_writer = await self.fs.async_fs.open(self.path, "wb")
 
         await self._opendal_writer.write(chunk)
@@ -223,11 +436,38 @@ class OpendalAsyncBufferedFile(AbstractAsyncStreamedFile):
             await self._commit_upload()
         return None
 
+    async def fsync(self) -> None:
+        """Make the bytes written so far durable, where the backend allows it"""
+        if self.closed:
+            raise ValueError("I/O operation on closed file")
+        if not self.writable():
+            raise io.UnsupportedOperation("not open for writing")
+        if not self.fs.durable_on_flush:
+            raise io.UnsupportedOperation(f"{self.fs.scheme} commits written data only on close()")
+
+        if self.offset is None:
+            self.offset = 0
+            await self._initiate_upload()
+        if self._opendal_writer is None and not self._append_via_write:
+            self._opendal_writer = await self.fs.async_fs.open(self.path, "wb")
+        if await self._upload_chunk() is not False:
+            self.offset += self.buffer.seek(0, 2)
+            self.buffer = io.BytesIO()
+        if self._opendal_writer is not None:
+            flush = getattr(self._opendal_writer, "flush", None)
+            if flush is None:
+                raise io.UnsupportedOperation("this OpenDAL version cannot flush async writers")
+            await flush()
+
     async def _initiate_upload(self) -> None:
         if self._initiated:
             return
 
-        if self._exclusive_create and await self.fs.async_fs.exists(self.path):
+        if (
+            self._exclusive_create
+            and self._check_exists
+            and await self.fs.async_fs.exists(self.path)
+        ):
             raise FileExistsError(self.path)
 
         if self.mode == "ab":
@@ -236,6 +476,7 @@ class OpendalAsyncBufferedFile(AbstractAsyncStreamedFile):
                 self._append_via_write = True
                 self.offset = self.loc
             else:
+                self.fs._emulate("write_can_append", "rewriting the object on every append")
                 try:
                     existing = await self.fs.async_fs.read(self.path)
                 except (FileNotFoundError, NotFound):
@@ -252,7 +493,11 @@ class OpendalAsyncBufferedFile(AbstractAsyncStreamedFile):
             return
 
         if self._opendal_writer is None:
-            await self.fs.async_fs.write(self.path, b"")
+            options = self.fs._create_options() if self._exclusive_create else {}
+            try:
+                await self.fs.async_fs.write(self.path, b"", **options)
+            except ConditionNotMatch as err:
+                raise FileExistsError(self.path) from err
             return
 
         try:
@@ -260,9 +505,9 @@ class OpendalAsyncBufferedFile(AbstractAsyncStreamedFile):
         finally:
             self._opendal_writer = None
 
-    async def close(self):
+    async def close(self) -> WriteResult | None:
         if self.closed:
-            return
+            return self.result
 
         try:
             await super().close()
@@ -272,3 +517,7 @@ class OpendalAsyncBufferedFile(AbstractAsyncStreamedFile):
                     await self._opendal_writer.close()
                 finally:
                     self._opendal_writer = None
+        if self._digest is not None:
+            etag = getattr(await self.fs.async_fs.stat(self.path), "etag", None)
+            self.result = WriteResult(self.path, self.loc, etag or None, self._digest.hexdigest())
+        return self.result
@@ -0,0 +1,57 @@
+from __future__ import annotations
+
+from collections.abc import Iterable, Mapping
+from datetime import datetime, timezone
+from typing import Any
+
+from .summary import TreeSummary
+
+# Column order for listings; keys outside these are appended as found.
+_LISTING_COLUMNS = ("name", "size", "type", "mtime", "etag", "is_marker")
+
+
+def _rows(listing: Any) -> list[dict[str, Any]]:
+    if isinstance(listing, TreeSummary):
+        return [
+            {"size_below": bound, "files": count}
+            for bound, count in sorted(listing.histogram.items())
+        ]
+    if isinstance(listing, Mapping):
+        listing = listing.values()
+    rows = [entry if isinstance(entry, Mapping) else {"name": entry} for entry in listing]
+    return [dict(row) for row in rows]
+
+
+def to_arrow(listing: Iterable[Any] | Mapping[str, Any] | TreeSummary) -> Any:
+    """Build a ``pyarrow.Table`` from a listing or a summary
+
+    Accepts what ``ls``/``find``/``glob`` return, with or without details.
+    ``mtime`` becomes a UTC timestamp column. A ``TreeSummary`` yields its
+    size histogram, one row per ``size_below`` bound.
+    """
+    import pyarrow as pa
+
+    rows = _rows(listing)
+    columns: dict[str, list[Any]] = {}
+    names = [c for c in _LISTING_COLUMNS if any(c in row for row in rows)]
+    names += sorted({key for row in rows for key in row} - set(names))
+    for name in names:
+        columns[name] = [row.get(name) for row in rows]
+    if "mtime" in columns:
+        columns["mtime"] = [
+            None if m is None else datetime.fromtimestamp(m, timezone.utc)
+            for m in columns["mtime"]
+        ]
+    return pa.table(columns)
+
+
+def to_pandas(listing: Iterable[Any] | Mapping[str, Any] | TreeSummary) -> Any:
+    """Build a ``pandas.DataFrame`` from a listing or a summary via Arrow"""
+    return to_arrow(listing).to_pandas()
+
+
+def to_polars(listing: Iterable[Any] | Mapping[str, Any] | TreeSummary) -> Any:
+    """Build a ``polars.DataFrame`` from a listing or a summary via Arrow"""
+    import polars as pl
+
+    return pl.from_arrow(to_arrow(listing))
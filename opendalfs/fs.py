@@ -1,17 +1,174 @@
 from __future__ import annotations
 
+import asyncio
+import concurrent.futures
+import contextlib
+import functools
+import hashlib
+import inspect
+import json
+import mmap
+import os
+import re
+import time
+import uuid
+import weakref
+from collections.abc import Awaitable, Callable, Iterable, Mapping
+from datetime import datetime, timedelta, timezone
 from glob import has_magic
-from typing import Any
+from typing import Any, TypeVar, cast
 
-from fsspec.asyn import AsyncFileSystem
+from fsspec.asyn import AsyncFileSystem, sync, sync_wrapper
 from fsspec.implementations.local import trailing_sep
+from fsspec.utils import glob_translate, tokenize
 import logging
 from opendal import AsyncOperator, Operator
+from opendal.layers import ConcurrentLimitLayer, RetryLayer
 from .file import OpendalAsyncBufferedFile, OpendalBufferedFile
-from opendal.exceptions import NotFound, Unsupported
+from .batch import RetryBudget, run_batch
+from .budget import get_memory_budget
+from .cache import DiskCache
+from .delete import (
+    DEFAULT_DELETE_GROUP_SIZE,
+    DeletePlan,
+    DeleteReport,
+)
+from .diagnostics import DiagnosticReport, run_diagnostics
+from .exceptions import (
+    CapabilityError,
+    FileChangedError,
+    KeyTooLongError,
+    ListingInterrupted,
+    PartialDeleteError,
+    translate_error,
+)
+from .globbing import expand_braces
+from .mapper import OpendalMapper
+from .metrics import OperationStats, ReadTiming, RuntimeStats, StatsRecorder
+from .mirror import LocalMirror
+from .move import MovePlan, MoveReport
+from .operator import (
+    BlockingAsyncOperator,
+    HookedOperator,
+    call_limit,
+    translate_handled_errors,
+)
+from .presign import PRESIGN_METHODS, SignedRequest
+from .scheduler import PriorityScheduler, priority_rank
+from .signing import signing_details
+from .summary import TreeSummary
+from opendal.exceptions import (
+    ConditionNotMatch,
+    NotFound,
+    PermissionDenied,
+    Unexpected,
+    Unsupported,
+)
 
 logger = logging.getLogger("opendalfs")
 
+T = TypeVar("T")
+
+# Presigned request standing in for each operation when showing its signature.
+_SIGNING_PROBES = {
+    "stat": "presign_stat",
+    "exists": "presign_stat",
+    "write": "presign_write",
+    "delete": "presign_delete",
+}
+
+# User metadata key holding the SHA-256 of objects written idempotently.
+_IDEMPOTENCY_KEY = "opendalfs-content-sha256"
+
+# Marker file batch jobs write once all the data under a prefix is in place.
+_SUCCESS_MARKER = "_SUCCESS"
+
+# Errors worth another attempt when a caller asks for per-call retries.
+_TRANSIENT_ERRORS = (Unexpected, TimeoutError)
+
+# Slice size used when streaming buffers that are not ``bytes`` to a writer.
+_WRITE_CHUNK_SIZE = 8 * 2**20
+
+# S3's minimum part size; smaller file writes gain nothing from multipart.
+_MULTIPART_THRESHOLD = 5 * 2**20
+
+# Piece size of resumable reads; a failure loses at most one piece.
+_RESUME_CHUNK_SIZE = 8 * 2**20
+
+# Backends whose writers put bytes in place as they are written, so a file
+# can be made durable before close. Object stores only commit on close.
+_DURABLE_ON_FLUSH = frozenset({"fs"})
+
+# Headers ``update_metadata`` carries over when it rewrites an object.
+_REWRITTEN_HEADERS = (
+    "content_type",
+    "cache_control",
+    "content_disposition",
+    "content_encoding",
+    "user_metadata",
+)
+
+# Upper bound on objects whose content is kept alongside their metadata.
+_INLINE_CACHE_ENTRIES = 4096
+
+# Documented maximum key length in UTF-8 bytes, per service.
+_MAX_KEY_LENGTH = {
+    "s3": 1024,
+    "gcs": 1024,
+    "azblob": 1024,
+    "oss": 1023,
+    "cos": 850,
+}
+
+
+def _log_level(level: int | str) -> int:
+    if isinstance(level, int):
+        return level
+    resolved = logging.getLevelName(level.upper())
+    if not isinstance(resolved, int):
+        raise ValueError(f"unknown log level: {level!r}")
+    return resolved
+
+
+async def _iter_chunks(source: Any, chunk_size: int):
+    """Yield chunks from a buffer, a file-like object or an (async) iterable
+
+    Buffers are sliced without copying them as a whole.
+    """
+    try:
+        view = memoryview(source)
+    except TypeError:
+        pass
+    else:
+        if not view.c_contiguous:
+            view = memoryview(view.tobytes())
+        view = view.cast("B")
+        for offset in range(0, len(view), chunk_size):
+            yield view[offset : offset + chunk_size]
+        return
+
+    read = getattr(source, "read", None)
+    if read is not None:
+        while True:
+            if inspect.iscoroutinefunction(read):
+                chunk = await read(chunk_size)
+            else:
+                chunk = await asyncio.to_thread(read, chunk_size)
+            if not chunk:
+                return
+            yield chunk
+    elif hasattr(source, "__aiter__"):
+        async for chunk in source:
+            yield chunk
+    else:
+        iterator = iter(source)
+        while True:
+            chunk = await asyncio.to_thread(next, iterator, None)
+            if chunk is None:
+                return
+            yield chunk
+
+
 class OpendalFileSystem(AsyncFileSystem):
     """OpenDAL implementation of fsspec AsyncFileSystem.
 
@@ -22,12 +179,46 @@ class OpendalFileSystem(AsyncFileSystem):
     async_impl = True
     retries = 5  # Like s3fs
 
+    def __init_subclass__(cls, **kwargs: Any) -> None:
+        super().__init_subclass__(**kwargs)
+        translate_handled_errors(cls)
+
     def __init__(
         self,
         scheme: str,
         *args: Any,
         asynchronous: bool = False,
         loop=None,
+        read_callback: Callable[[ReadTiming], None] | None = None,
+        cache_dir: str | None = None,
+        cache_max_size: int | None = 10 * 2**30,
+        use_mmap: bool = False,
+        timeout: float | None = None,
+        io_timeout: float | None = None,
+        case_insensitive: bool = False,
+        trash_prefix: str | None = None,
+        list_page_size: int | None = None,
+        check_exists: bool = True,
+        multipart_threshold: int = _MULTIPART_THRESHOLD,
+        resume_reads: int = 0,
+        blocking: bool = False,
+        max_key_length: int | None = None,
+        sort_listings: bool = True,
+        log_requests: bool = False,
+        logging: bool = False,
+        log_level: int | str = "DEBUG",
+        debug_signing: bool = False,
+        inline_threshold: int | None = None,
+        readahead: int | None = None,
+        priority_slots: int | None = None,
+        max_concurrency: int | None = None,
+        replicas: list[dict[str, Any]] | None = None,
+        replica_cooldown: float = 30.0,
+        worker_threads: int | None = None,
+        thread_name: str = "opendalfs",
+        retry: bool | dict[str, Any] | None = None,
+        emulate_missing: bool = True,
+        idempotent_writes: bool = False,
         **kwargs: Any,
     ) -> None:
         """Initialize OpendalFileSystem.
@@ -40,13 +231,611 @@ class OpendalFileSystem(AsyncFileSystem):
             Whether to return async versions of methods (default: False)
         loop : event loop (optional)
             Specific event loop to use
+        read_callback : callable (optional)
+            Called with a ``ReadTiming`` after every read sent to the backend
+        cache_dir : str (optional)
+            Directory for a persistent cache of whole objects, keyed by ETag
+            and shareable between processes; ranged reads bypass it
+        cache_max_size : int (optional)
+            Bytes the ``cache_dir`` cache may hold before the least recently
+            used objects are evicted (default 10 GiB); ``None`` for no limit
+        use_mmap : bool
+            For the local ``fs`` backend, serve ``cat_file`` and file reads
+            from memory maps as zero-copy ``memoryview`` objects
+        timeout : float (optional)
+            Limit in seconds for each backend call, and each page of a
+            listing, whichever method makes it. For reads, writes, lists and
+            deletes it is the default, overridable per call with ``timeout=``;
+            listings and resumable reads apply it to each page or chunk
+        io_timeout : float (optional)
+            Limit in seconds for each read or write step of a streamed
+            transfer, so a stalled connection fails with ``TimeoutError``
+            instead of hanging the task; applies to the async API and the
+            files it opens
+        case_insensitive : bool
+            Resolve paths regardless of case through an index learned from
+            listings, e.g. for data migrated from Windows shares
+        trash_prefix : str (optional)
+            Make ``rm`` and ``rmdir`` move objects under this prefix, in a
+            folder named after the deletion time, instead of deleting them;
+            see ``empty_trash``
+        list_page_size : int (optional)
+            Entries requested per listing page, trading request count against
+            latency; can be overridden per ``ls`` call
+        check_exists : bool
+            Check for an existing object before exclusive (``xb``, ``mode=
+            "create"``) and append writes. Disable for write-only pipelines
+            that know their keys are fresh, to skip a HEAD per object; can be
+            overridden per ``open`` or ``pipe_file`` call. Conditional writes
+            need no HEAD and are used either way where the backend has them
+        multipart_threshold : int
+            File objects smaller than this many bytes are written with a
+            single PUT once closed; larger ones stream through a multipart
+            writer
+        resume_reads : int
+            How many times in a row a read failing mid-stream is resumed from
+            the last byte received, after checking that the ETag is unchanged
+        blocking : bool
+            Build only OpenDAL's blocking operator and serve async methods
+            from worker threads, for environments where OpenDAL's async
+            runtime cannot be started (musl wheels, restricted sandboxes)
+        max_key_length : int (optional)
+            Longest key in UTF-8 bytes, including ``root``, accepted for
+            writes; defaults to the documented limit of known services
+        sort_listings : bool
+            Return ``ls`` entries in lexicographic order like s3fs, whatever
+            order the backend lists them in
+        log_requests : bool
+            Record the path, range and duration of every read sent to the
+            backend, for debugging access patterns; see ``request_log``
+        logging : bool
+            Log every backend call with its paths, duration and any error to
+            the ``opendalfs`` logger, to see which requests a slow operation
+            issues
+        log_level : int or str
+            Level of the ``logging`` records
+        debug_signing : bool
+            When the service rejects a request as unauthorized, log the
+            canonical request and signature components of an equivalent
+            presigned one, secrets redacted, to debug ``SignatureDoesNotMatch``
+            from S3-compatible gateways
+        inline_threshold : int (optional)
+            Fetch the content of files up to this many bytes whenever their
+            metadata is fetched by ``info`` or ``ls(detail=True)``, and serve
+            later ``cat_file`` calls from it, e.g. for small config files
+        readahead : int (optional)
+            Default for files opened for reading: once reads are sequential,
+            fetch this many bytes past each one in the background; can be
+            overridden per ``open`` call
+        priority_slots : int (optional)
+            Run at most this many reads, writes and listings at once, handing
+            freed slots to waiting calls by their ``priority=`` (``"high"``,
+            ``"normal"`` or ``"low"``), so interactive reads are not stuck
+            behind bulk prefetches
+        max_concurrency : int (optional)
+            Send at most this many requests to the backend at once, through
+            OpenDAL's ``ConcurrentLimitLayer``, e.g. to keep parallel pyarrow
+            scans from overwhelming a self-hosted MinIO. Unlike
+            ``priority_slots`` it covers every request, file reads and writes
+            of the sync API included, without ordering them
+        replicas : list of dict (optional)
+            Fallback chain for reads: backend options, e.g. the ``endpoint``
+            and ``bucket`` of a secondary region, each overriding this
+            filesystem's own. Reads failing with a transient error on the
+            primary are retried on each replica in turn
+        replica_cooldown : float
+            Seconds an operator whose read failed is tried after the others
+        worker_threads : int (optional)
+            In ``blocking`` mode, run backend calls on a pool of this many
+            threads owned by this filesystem rather than asyncio's default
+            executor shared with the rest of the process. The Python bindings
+            run async calls on one process-wide Tokio runtime that cannot be
+            configured per operator, so this has no effect otherwise. The
+            pool shuts down once the filesystem is garbage collected
+        thread_name : str
+            Name prefix of the ``worker_threads`` pool's threads
+        retry : bool or dict (optional)
+            Retry transient backend errors (e.g. S3 503s) inside OpenDAL with
+            a ``RetryLayer``: ``True`` for its defaults, or a dict of its
+            options ``max_times``, ``min_delay``, ``max_delay``, ``factor``
+            and ``jitter``. Per-call ``retries=`` apply on top
+        emulate_missing : bool
+            Emulate copy, rename and append on backends lacking them, by
+            streaming, copying and deleting, or rewriting objects. Disable to
+            get a ``CapabilityError`` instead of the slower fallback
+        idempotent_writes : bool
+            Tag single writes with a hash of their content, and before a
+            per-call retry of one check whether the failed attempt landed
+            after all, so versioned buckets do not get a duplicate version
         **kwargs : dict
             Passed to backend implementation
         """
         super().__init__(asynchronous=asynchronous, loop=loop, *args, **kwargs)
         self.scheme = scheme
-        self.async_fs = AsyncOperator(scheme, *args, **kwargs)
-        self.operator: Operator = self.async_fs.to_operator()
+        self.read_callback = read_callback
+        self._request_log: list[ReadTiming] | None = [] if log_requests else None
+        self.logging = logging
+        self._stats = StatsRecorder()
+        self.log_level = _log_level(log_level)
+        self.debug_signing = debug_signing
+        self._disk_cache = (
+            DiskCache(cache_dir, max_size=cache_max_size) if cache_dir else None
+        )
+        self.use_mmap = use_mmap and scheme == "fs"
+        self.timeout = timeout
+        self.io_timeout = io_timeout
+        self.case_insensitive = case_insensitive
+        self._case_index: dict[str, str] = {}
+        self.trash_prefix = trash_prefix.strip("/") if trash_prefix else None
+        self.list_page_size = list_page_size
+        self.check_exists = check_exists
+        self.multipart_threshold = multipart_threshold
+        self.resume_reads = resume_reads
+        self.blocking = blocking
+        self.retry = retry
+        self.emulate_missing = emulate_missing
+        self.idempotent_writes = idempotent_writes
+        self.max_key_length = max_key_length or _MAX_KEY_LENGTH.get(scheme)
+        self.sort_listings = sort_listings
+        self.inline_threshold = inline_threshold
+        self.readahead = readahead
+        self._inline_cache: dict[str, bytes] = {}
+        self._prefetched: dict[tuple[str, int | None, int | None], bytes] = {}
+        self._scheduler = PriorityScheduler(priority_slots) if priority_slots else None
+        self.max_concurrency = max_concurrency
+        self._executor = (
+            concurrent.futures.ThreadPoolExecutor(worker_threads, thread_name_prefix=thread_name)
+            if worker_threads
+            else None
+        )
+        if self._executor is not None:
+            # Idle workers of a pool outliving its filesystem would never exit.
+            weakref.finalize(self, self._executor.shutdown, wait=False)
+        self._backend_args = args
+        self._backend_options = kwargs
+        self._replica_options = list(replicas or [])
+        self.replica_cooldown = replica_cooldown
+        self._build_operators()
+
+    def _build_operators(self) -> None:
+        async_operator, operator = self._make_operators(self._backend_options)
+        self.async_fs = cast(AsyncOperator, HookedOperator(self, async_operator, True))
+        self.operator = cast(Operator, HookedOperator(self, operator, False))
+        self._replicas = [
+            cast(
+                AsyncOperator,
+                HookedOperator(
+                    self, self._make_operators({**self._backend_options, **replica})[0], True
+                ),
+            )
+            for replica in self._replica_options
+        ]
+        self._replica_down_until: dict[int, float] = {}
+
+    def _make_operators(self, options: dict[str, Any]) -> tuple[Any, Any]:
+        args = self._backend_args
+        if self.blocking:
+            operator = Operator(self.scheme, *args, **options)
+            for layer in self._layers():
+                operator = operator.layer(layer)
+            async_operator: Any = BlockingAsyncOperator(operator, self._executor)
+        else:
+            async_operator = AsyncOperator(self.scheme, *args, **options)
+            for layer in self._layers():
+                async_operator = async_operator.layer(layer)
+            operator = async_operator.to_operator()
+        return async_operator, operator
+
+    def _layers(self) -> list[Any]:
+        """OpenDAL layers wrapping each operator built, innermost first"""
+        layers: list[Any] = []
+        if self.max_concurrency:
+            # Inside the retry layer, so backing off does not hold a permit.
+            layers.append(ConcurrentLimitLayer(self.max_concurrency))
+        if self.retry:
+            layers.append(self._retry_layer())
+        return layers
+
+    def _retry_layer(self) -> RetryLayer:
+        return RetryLayer(**(self.retry if isinstance(self.retry, dict) else {}))
+
+    async def _reconfigure(self, drain_timeout: float | None = 30.0, **options: Any) -> None:
+        """Rebuild the operators in place with backend options changed
+
+        For rotating endpoints or credentials in long-running services that
+        share this filesystem across their code. New calls use the new
+        operators at once; this waits up to ``drain_timeout`` seconds for
+        calls already sent on the old ones to finish. Files opened before
+        keep the operator they were opened with. Cached listings are dropped.
+        """
+        old = (self.async_fs, self.operator)
+        self._backend_options = {**self._backend_options, **options}
+        self._build_operators()
+        self.storage_options.update(options)
+        self.invalidate_cache()
+        self._case_index.clear()
+
+        deadline = None if drain_timeout is None else time.monotonic() + drain_timeout
+        while any(cast(HookedOperator, op).inflight for op in old):
+            if deadline is not None and time.monotonic() >= deadline:
+                logger.warning("Reconfigured with calls still running on the old operator")
+                return
+            await asyncio.sleep(0.01)
+
+    reconfigure = sync_wrapper(_reconfigure)
+
+    @property
+    def durable_on_flush(self) -> bool:
+        """Whether ``fsync()`` on open files makes bytes durable before close"""
+        return self.scheme in _DURABLE_ON_FLUSH
+
+    # Hooks for subclasses. Every operator call taking paths goes through
+    # them, so a subclass can e.g. confine a tenant to a prefix without
+    # overriding individual methods.
+    def _to_backend_path(self, path: str) -> str:
+        """Map a user-facing path to the key sent to the backend"""
+        if self.case_insensitive:
+            stripped = path.rstrip("/")
+            actual = self._case_index.get(stripped.lower())
+            if actual is not None:
+                return actual + path[len(stripped) :]
+        return path
+
+    def _from_backend_path(self, path: str) -> str:
+        """Map a key returned by a listing back to a user-facing path"""
+        return path
+
+    def _before_operation(self, operation: str, path: str) -> None:
+        """Called before each operator call, e.g. to enforce access rules"""
+
+    def _after_operation(self, operation: str, path: str, result: Any) -> Any:
+        """Called with each operator result; the return value replaces it"""
+        return result
+
+    def with_credentials(self, **credentials: Any) -> OpendalFileSystem:
+        """Return a filesystem for the same backend with other credentials.
+
+        ``credentials`` override the matching constructor options, e.g.
+        ``access_key_id``/``secret_access_key``/``session_token`` for s3. The
+        derived filesystem builds its own operator and bypasses the instance
+        cache so per-user filesystems are not kept alive.
+        """
+        options = {**self.storage_options, **credentials}
+        return type(self)(*self.storage_args, skip_instance_cache=True, **options)
+
+    @staticmethod
+    def _pop_overrides(kwargs: dict[str, Any]) -> dict[str, Any]:
+        """Take the per-call ``retries``/``timeout``/``priority`` options out of kwargs"""
+        return {
+            key: kwargs.pop(key)
+            for key in ("retries", "timeout", "priority", "retry_budget")
+            if key in kwargs
+        }
+
+    async def _call(
+        self,
+        op: Callable[[], Awaitable[T]],
+        retries: int | None = None,
+        timeout: float | None = None,
+        priority: str = "normal",
+        retry_budget: RetryBudget | None = None,
+        steps: bool = False,
+    ) -> T:
+        """Run a backend call with per-call retry and timeout overrides
+
+        ``retries`` is the number of extra attempts after a transient error
+        (none by default) and ``timeout`` the limit in seconds for each
+        attempt, defaulting to the filesystem's ``timeout``. With ``steps``,
+        ``op`` makes several requests, e.g. a listing fetching pages or a read
+        in chunks, and ``timeout`` bounds each of them instead, so large
+        listings and downloads are not cut short. ``priority`` orders the call
+        against others waiting for ``priority_slots``; a ``retry_budget``
+        shared by a batch bounds its retries as a whole.
+        """
+        retries = retries or 0
+        if timeout is None:
+            timeout = self.timeout
+        priority_rank(priority)
+
+        attempt = 0
+        while True:
+            try:
+                slot = (
+                    self._scheduler.slot(priority)
+                    if self._scheduler is not None
+                    else contextlib.nullcontext()
+                )
+                async with slot:
+                    if timeout is None:
+                        return await op()
+                    if steps:
+                        with call_limit(timeout):
+                            return await op()
+                    with call_limit(None):
+                        return await asyncio.wait_for(op(), timeout)
+            except _TRANSIENT_ERRORS:
+                if attempt >= retries:
+                    raise
+                delay = min(0.1 * 2 ** (attempt + 1), 5.0)
+                if retry_budget is not None and not retry_budget.spend(delay):
+                    raise
+                attempt += 1
+                await asyncio.sleep(delay)
+
+    async def _read(
+        self,
+        path: str,
+        offset: int = 0,
+        size: int | None = None,
+        if_match: str | None = None,
+        **overrides: Any,
+    ) -> bytes:
+        """Read bytes from the backend, reporting timing to ``read_callback``
+
+        With ``if_match``, raises ``FileChangedError`` unless the object still
+        has that ETag.
+        """
+        options: dict[str, Any] = {}
+        if offset:
+            options["offset"] = offset
+        if size is not None:
+            options["size"] = size
+        if if_match is not None:
+            options["if_match"] = if_match
+
+        reservation: Any = contextlib.nullcontext()
+        budget = get_memory_budget()
+        if budget is not None:
+            expected = size
+            if expected is None:
+                expected = max(0, (await self._info(path))["size"] - offset)
+            reservation = budget.reserve(expected)
+
+        start = time.perf_counter()
+        try:
+            async with reservation:
+                if self.resume_reads:
+                    data = await self._call(
+                        lambda: self._read_resumable(path, offset, size, if_match),
+                        steps=True,
+                        **overrides,
+                    )
+                else:
+                    data = await self._call(
+                        lambda: self._read_replicated(path, **options), **overrides
+                    )
+        except ConditionNotMatch as err:
+            raise FileChangedError(path, if_match, None) from err
+        self._report_read(path, offset, size, len(data), start)
+        return data
+
+    async def _read_replicated(self, path: str, **options: Any) -> bytes:
+        """Read from the primary, falling back along ``replicas``
+
+        An operator failing with a transient error is tried last for the next
+        ``replica_cooldown`` seconds, so reads stop waiting on a region that
+        is down and return to it once it has recovered.
+        """
+        if not self._replicas:
+            return await self.async_fs.read(path, **options)
+
+        chain = [self.async_fs, *self._replicas]
+        now = time.monotonic()
+        order = sorted(
+            range(len(chain)), key=lambda i: self._replica_down_until.get(i, 0.0) > now
+        )
+        for index in order[:-1]:
+            try:
+                data = await chain[index].read(path, **options)
+            except _TRANSIENT_ERRORS as err:
+                self._replica_down_until[index] = time.monotonic() + self.replica_cooldown
+                logger.warning(
+                    "Read of %s failed on replica %d, trying the next: %r", path, index, err
+                )
+                continue
+            self._replica_down_until.pop(index, None)
+            return data
+
+        try:
+            data = await chain[order[-1]].read(path, **options)
+        except _TRANSIENT_ERRORS:
+            self._replica_down_until[order[-1]] = time.monotonic() + self.replica_cooldown
+            raise
+        self._replica_down_until.pop(order[-1], None)
+        return data
+
+    async def _read_resumable(
+        self, path: str, offset: int, size: int | None, if_match: str | None = None
+    ) -> bytes:
+        """Stream a range, reopening at the last received byte after errors
+
+        A replaced object fails with ``FileChangedError`` instead of mixing
+        two versions: readers are conditional on its ETag where the backend
+        supports it, otherwise the ETag is compared after each failure. That
+        ETag is ``if_match`` or the one of a cached listing; without either it
+        is fetched after the first failure. Reads that never fail cost no
+        extra request, but a change before that failure goes unnoticed.
+        """
+        etag = if_match
+        if etag is None:
+            cached = self._info_from_cache(path)
+            etag = cached.get("etag") if cached else None
+        pinned = getattr(self.async_fs.capability(), "read_with_if_match", False)
+        end = None if size is None else offset + size
+
+        out = bytearray()
+        position = offset
+        failures = 0
+        reader = None
+        try:
+            while end is None or position < end:
+                try:
+                    if reader is None:
+                        options = {"if_match": etag} if etag and pinned else {}
+                        reader = await self.async_fs.open(path, "rb", **options)
+                        await reader.seek(position)
+                    length = _RESUME_CHUNK_SIZE if end is None else end - position
+                    chunk = await reader.read(min(_RESUME_CHUNK_SIZE, length))
+                except _TRANSIENT_ERRORS:
+                    failures += 1
+                    if failures > self.resume_reads:
+                        raise
+                    logger.debug("Resuming read of %s at byte %d", path, position)
+                    if reader is not None:
+                        broken, reader = reader, None
+                        with contextlib.suppress(Exception):
+                            await broken.close()
+                    await asyncio.sleep(min(0.1 * 2**failures, 5.0))
+                    if etag is None or not pinned:
+                        current = getattr(await self.async_fs.stat(path), "etag", None)
+                        if etag is not None and current != etag:
+                            raise FileChangedError(path, etag, current)
+                        etag = current
+                    continue
+                except ConditionNotMatch as err:
+                    raise FileChangedError(path, etag, None) from err
+                except NotFound as err:
+                    raise translate_error(err, path) from err
+                if not chunk:
+                    break
+                out += chunk
+                position += len(chunk)
+                failures = 0
+        finally:
+            if reader is not None:
+                await reader.close()
+        return bytes(out)
+
+    def _read_blocking(
+        self,
+        path: str,
+        offset: int = 0,
+        size: int | None = None,
+        if_match: str | None = None,
+    ) -> bytes:
+        """Synchronous counterpart of ``_read`` for blocking file objects"""
+        options: dict[str, Any] = {}
+        if offset:
+            options["offset"] = offset
+        if size is not None:
+            options["size"] = size
+        if if_match is not None:
+            options["if_match"] = if_match
+
+        reservation: Any = contextlib.nullcontext()
+        budget = get_memory_budget()
+        if budget is not None:
+            expected = size
+            if expected is None:
+                expected = max(0, self.info(path)["size"] - offset)
+            reservation = budget.reserve_blocking(expected)
+
+        start = time.perf_counter()
+        try:
+            with reservation:
+                data = self.operator.read(path, **options)
+        except ConditionNotMatch as err:
+            raise FileChangedError(path, if_match, None) from err
+        self._report_read(path, offset, size, len(data), start)
+        return data
+
+    def _log_signing(self, operation: str, path: Any) -> None:
+        """Log how a request on path is signed, after the service rejected one
+
+        OpenDAL signs inside its native code, so the details come from a
+        presigned request for the same key, signed with the same credentials,
+        region and addressing style.
+        """
+        presign = _SIGNING_PROBES.get(operation, "presign_read")
+        operator = cast(HookedOperator, self.operator).inner
+        try:
+            request = getattr(operator, presign)(self._to_backend_path(str(path)), 60)
+        except Exception as err:
+            logger.warning(
+                "%s %s was rejected; cannot show its signature: %r", operation, path, err
+            )
+            return
+        details = signing_details(request.method, request.url, dict(request.headers))
+        logger.warning(
+            "%s %s was rejected; signing details, secrets redacted:\n%s",
+            operation,
+            path,
+            "\n".join(f"{name}: {value}" for name, value in details.items()),
+        )
+
+    def _emulate(self, capability: str, alternative: str) -> None:
+        """Allow falling back to ``alternative``, or raise ``CapabilityError``"""
+        if not self.emulate_missing:
+            raise CapabilityError(self.scheme, capability, alternative)
+
+    def _create_options(self) -> dict[str, Any]:
+        """Options making a single write fail when the object exists, if any"""
+        if getattr(self.async_fs.capability(), "write_with_if_not_exists", False):
+            return {"if_not_exists": True}
+        return {}
+
+    def _pinned_etag(self, details: dict[str, Any]) -> str | None:
+        """ETag that reads of an open file are made conditional on, if any"""
+        if not getattr(self.operator.capability(), "read_with_if_match", False):
+            return None
+        return details.get("etag")
+
+    def _report_read(
+        self, path: str, offset: int, size: int | None, nbytes: int, start: float
+    ) -> None:
+        if self.read_callback is None and self._request_log is None:
+            return
+        elapsed = time.perf_counter() - start
+        timing = ReadTiming(
+            path=path,
+            offset=offset,
+            size=size,
+            nbytes=nbytes,
+            elapsed=elapsed,
+        )
+        if self._request_log is not None:
+            self._request_log.append(timing)
+        if self.read_callback is not None:
+            self.read_callback(timing)
+
+    def request_log(self, clear: bool = False) -> list[ReadTiming]:
+        """Reads sent to the backend so far, when ``log_requests`` is enabled"""
+        if self._request_log is None:
+            raise RuntimeError("request logging is off, pass log_requests=True")
+        log = list(self._request_log)
+        if clear:
+            del self._request_log[: len(log)]
+        return log
+
+    def stats(self, reset: bool = False) -> dict[str, OperationStats]:
+        """Cumulative request, error and byte counts per backend operation
+
+        Keyed by operator call (``read``, ``write``, ``stat``, ``list``...),
+        plus ``stream_read`` and ``stream_write`` for each chunk moved through
+        an open reader or writer. Retries count as separate requests.
+        """
+        return self._stats.snapshot(reset)
+
+    def runtime_stats(self) -> RuntimeStats:
+        """What is running and waiting right now, to diagnose stalls
+
+        Gathered from the calling thread without going through the event
+        loop, so it still answers while the loop is blocked.
+        """
+        loop = self.loop
+        scheduler = self._scheduler
+        executor = self._executor
+        return RuntimeStats(
+            inflight=sum(
+                cast(HookedOperator, op).inflight for op in (self.async_fs, self.operator)
+            ),
+            loop_tasks=len(asyncio.all_tasks(loop)) if loop is not None else 0,
+            slots_in_use=scheduler.active if scheduler is not None else 0,
+            queued=scheduler.waiting if scheduler is not None else 0,
+            # The pool exposes no public counters.
+            worker_threads=len(executor._threads) if executor is not None else 0,
+            worker_queue=executor._work_queue.qsize() if executor is not None else 0,
+        )
 
     @staticmethod
     def _fsspec_type_from_mode(mode: Any) -> str:
@@ -58,13 +847,22 @@ class OpendalFileSystem(AsyncFileSystem):
 
     # Async implementations using Rust's async methods directly
     #
-    async def _ls(self, path: str, detail=True, **kwargs):
-        """List contents of path"""
+    async def _ls(
+        self, path: str, detail: bool = True, **kwargs: Any
+    ) -> list[str] | list[dict[str, Any]]:
+        """List contents of path
+
+        A listing failing after some pages raises ``ListingInterrupted`` with
+        the entries received so far; pass its ``start_after`` to resume.
+        """
         path = self._strip_protocol(path)
         cache_path = path.rstrip("/")
         refresh = bool(kwargs.pop("refresh", False))
+        page_size = kwargs.pop("list_page_size", self.list_page_size)
+        start_after = kwargs.pop("start_after", None)
+        list_options: dict[str, Any] = {"limit": page_size} if page_size else {}
 
-        if detail and not refresh:
+        if detail and not refresh and start_after is None:
             try:
                 cached = self._ls_from_cache(cache_path)
             except FileNotFoundError:
@@ -73,44 +871,373 @@ class OpendalFileSystem(AsyncFileSystem):
                 return cached
 
         list_path = path
-        if path and not path.endswith("/"):
+        if not path:
+            list_path = "/"
+        elif not path.endswith("/"):
             list_path = path + "/"
 
-        lister = await self.async_fs.list(list_path)
+        # Kept across retries, which resume after the last entry received.
+        seen: set[str] = set()
+        received: list[str] = []
+        listed: dict[str, Any] = {}
+        last_key = start_after
 
-        paths: list[str] = []
-        async for entry in lister:
-            paths.append(entry.path)
+        async def collect() -> None:
+            nonlocal last_key
+            options = dict(list_options)
+            if last_key is not None:
+                options["start_after"] = last_key
+            lister = await self.async_fs.list(list_path, **options)
+            async for entry in lister:
+                last_key = entry.path
+                name = self._from_backend_path(entry.path)
+                # Some backends list the prefix itself or repeat entries
+                # across pages; neither belongs in the result.
+                if name in ("", "/", list_path) or name in seen:
+                    continue
+                seen.add(name)
+                received.append(name)
+                metadata = getattr(entry, "metadata", None)
+                if metadata is not None:
+                    listed[name] = metadata
+
+        try:
+            await self._call(collect, steps=True, **self._pop_overrides(kwargs))
+        except Exception as err:
+            if last_key is None or last_key == start_after:
+                raise
+            raise ListingInterrupted(path, received, last_key) from err
+        paths = sorted(received) if self.sort_listings else received
+        if self.case_insensitive:
+            for name in paths:
+                stripped = name.rstrip("/")
+                self._case_index[stripped.lower()] = stripped
 
         if not detail:
             return paths
 
+        # Backends that list sizes save a stat per entry.
+        from_listing = getattr(self.async_fs.capability(), "list_has_content_length", False)
         out: list[dict[str, Any]] = []
         for p in paths:
-            out.append(await self._info(p))
-        self.dircache[cache_path] = out
+            if from_listing and p in listed:
+                out.append(await self._info_from_metadata(p, listed[p]))
+            else:
+                out.append(await self._info(p))
+        if start_after is None:
+            self.dircache[cache_path] = out
         return out
 
-    async def _info(self, path: str, **kwargs):
+    async def _ls_iter(self, path: str, detail: bool = False, **kwargs: Any):
+        """Yield the contents of path as the backend lists them
+
+        Unlike ``ls``, entries are neither collected nor sorted, so memory
+        stays flat for directories with millions of keys. Entries come in
+        backend order and the listing cache is not used.
+        """
+        path = self._strip_protocol(path)
+        page_size = kwargs.pop("list_page_size", self.list_page_size)
+        list_path = path.rstrip("/") + "/" if path.strip("/") else "/"
+        options: dict[str, Any] = {"limit": page_size} if page_size else {}
+        from_listing = detail and getattr(
+            self.async_fs.capability(), "list_has_content_length", False
+        )
+
+        lister = await self.async_fs.list(list_path, **options)
+        async for entry in lister:
+            name = self._from_backend_path(entry.path)
+            if name in ("", "/", list_path):
+                continue
+            if not detail:
+                yield name
+                continue
+            metadata = getattr(entry, "metadata", None)
+            if from_listing and metadata is not None:
+                yield await self._info_from_metadata(name, metadata)
+            else:
+                yield await self._info(name)
+
+    def ls_iter(self, path: str, detail: bool = False, **kwargs: Any):
+        """Blocking counterpart of ``_ls_iter``, one entry at a time"""
+        entries = self._ls_iter(path, detail=detail, **kwargs)
+        while True:
+            try:
+                yield sync(self.loop, entries.__anext__)
+            except StopAsyncIteration:
+                return
+
+    async def _find(
+        self,
+        path: str,
+        maxdepth: int | None = None,
+        withdirs: bool = False,
+        **kwargs: Any,
+    ) -> list[str] | dict[str, dict[str, Any]]:
+        """List everything below path with one recursive listing
+
+        fsspec's default walks the tree with a listing per directory, which
+        is slow on object stores with deep prefixes. Directories are derived
+        from the keys found, so prefixes without marker objects are included
+        with ``withdirs``.
+        """
+        if maxdepth is not None and maxdepth < 1:
+            raise ValueError("maxdepth must be at least 1")
+        detail = kwargs.pop("detail", False)
+        path = self._strip_protocol(path).rstrip("/")
+        list_path = f"{path}/" if path else "/"
+        files: set[str] = set()
+        dirs: set[str] = set()
+        listed: dict[str, Any] = {}
+
+        async def collect() -> None:
+            files.clear()
+            dirs.clear()
+            lister = await self._list_recursive(list_path)
+            async for entry in lister:
+                name = self._from_backend_path(entry.path)
+                relative = name[len(path) :] if path else name
+                parts = relative.strip("/").split("/")
+                if parts == [""]:
+                    continue
+                depth = len(parts) if maxdepth is None else min(len(parts), maxdepth + 1)
+                if withdirs:
+                    for level in range(1, depth):
+                        dirs.add("/".join(filter(None, [path, *parts[:level]])))
+                if name.endswith("/"):
+                    if withdirs and (maxdepth is None or len(parts) <= maxdepth):
+                        dirs.add(name.rstrip("/"))
+                elif maxdepth is None or len(parts) <= maxdepth:
+                    files.add(name)
+                    metadata = getattr(entry, "metadata", None)
+                    if metadata is not None:
+                        listed[name] = metadata
+
+        await self._call(collect, steps=True, **self._pop_overrides(kwargs))
+        if not files and not dirs:
+            if path and await self._isfile(path):
+                files.add(path)
+        elif withdirs and path:
+            dirs.add(path)
+
+        names = sorted(files | dirs)
+        if not detail:
+            return names
+        from_listing = getattr(self.async_fs.capability(), "list_has_content_length", False)
+        infos = await run_batch(
+            [
+                self._info_from_metadata(name, listed[name])
+                if from_listing and name in listed
+                else self._info(name)
+                for name in sorted(files)
+            ],
+            on_error="return",
+        )
+        out: dict[str, dict[str, Any]] = {
+            name: {"name": name, "size": 0, "type": "directory"} for name in dirs
+        }
+        for name, info in zip(sorted(files), infos):
+            # Entries deleted since they were listed are left out.
+            if not isinstance(info, BaseException):
+                out[name] = info
+        return {name: out[name] for name in names if name in out}
+
+    async def _ls_sharded(
+        self,
+        path: str,
+        shards: int = 16,
+        detail: bool = False,
+        max_fanout_depth: int = 3,
+    ) -> list[str] | dict[str, dict[str, Any]]:
+        """List every file below path, with listings of subtrees in parallel
+
+        Directories are expanded level by level, at most ``max_fanout_depth``
+        deep, until there are ``shards`` of them; each is then listed
+        recursively as its own shard, ``shards`` at a time. Suits bucket
+        inventories where one sequential listing would take hours.
+        """
+        path = self._strip_protocol(path).rstrip("/")
+        files: list[str] = []
+        dirs = [path]
+        for _ in range(max_fanout_depth):
+            if len(dirs) >= shards:
+                break
+            listings = await run_batch(
+                [self._ls(d, detail=False, refresh=True) for d in dirs], limit=shards
+            )
+            dirs = []
+            for names in listings:
+                for name in names:
+                    (dirs if name.endswith("/") else files).append(name.rstrip("/"))
+            if not dirs:
+                break
+
+        found = await run_batch([self._find(d) for d in dirs], limit=shards)
+        names = sorted(set(files).union(*found))
+        if not detail:
+            return names
+        infos = await run_batch([self._info(name) for name in names], on_error="return")
+        return {
+            name: info
+            for name, info in zip(names, infos)
+            if not isinstance(info, BaseException)
+        }
+
+    ls_sharded = sync_wrapper(_ls_sharded)
+
+    async def _du(
+        self, path: str, total: bool = True, maxdepth: int | None = None, **kwargs: Any
+    ) -> int | dict[str, int]:
+        """Space used by the files below path
+
+        Where the backend lists sizes, they are summed as the recursive
+        listing streams in, with no stat per file and, for ``total=True``, no
+        list of entries kept.
+        """
+        listed_sizes = getattr(self.async_fs.capability(), "list_has_content_length", False)
+        if maxdepth is not None or kwargs.get("withdirs") or not listed_sizes:
+            return await super()._du(path, total=total, maxdepth=maxdepth, **kwargs)
+
+        path = self._strip_protocol(path).rstrip("/")
+        used = 0
+        sizes: dict[str, int] = {}
+        lister = await self._list_recursive(f"{path}/" if path else "/")
+        async for entry in lister:
+            name = self._from_backend_path(entry.path)
+            if name.endswith("/"):
+                continue
+            metadata = getattr(entry, "metadata", None)
+            if metadata is not None:
+                size = metadata.content_length
+            else:
+                size = (await self._info(name))["size"]
+            used += size
+            if not total:
+                sizes[name] = size
+        if not used and not sizes and path and await self._isfile(path):
+            size = (await self._info(path))["size"]
+            used, sizes = size, {path: size}
+        return used if total else sizes
+
+    async def _list_recursive(self, path: str) -> Any:
+        try:
+            return await self.async_fs.list(path, recursive=True)
+        except TypeError:
+            # Bindings predating ``recursive=`` list recursively with scan.
+            return await self.async_fs.scan(path)
+
+    async def _info(self, path: str, **kwargs: Any) -> dict[str, Any]:
         """Get path info"""
         logger.debug(f"Getting info for: {path}")
+        if not path.strip("/"):
+            if not await self._bucket_exists():
+                raise FileNotFoundError(path)
+            return {"name": "", "size": 0, "type": "directory"}
         try:
             info = await self.async_fs.stat(path)
         except NotFound as err:
-            raise FileNotFoundError(path) from err
-        return {
+            if await self._learn_case(path):
+                return await self._info(path, **kwargs)
+            raise translate_error(err, path) from err
+        return await self._info_from_metadata(path, info)
+
+    async def _info_from_metadata(self, path: str, info: Any) -> dict[str, Any]:
+        out = {
             "name": path,
             "size": info.content_length,
             "type": self._fsspec_type_from_mode(info.mode),
         }
+        etag = getattr(info, "etag", None)
+        if etag:
+            out["etag"] = etag
+        content_md5 = getattr(info, "content_md5", None)
+        if content_md5:
+            out["content_md5"] = content_md5
+        version = getattr(info, "version", None)
+        if version:
+            out["version_id"] = version
+        modified = getattr(info, "last_modified", None)
+        if modified is not None:
+            # A float keeps microseconds, so sync tools can compare mtimes.
+            out["mtime"] = modified.timestamp()
+        if path.endswith("/"):
+            # A key ending in "/" is a directory whether the backend reports
+            # it as a zero-byte object (a marker) or synthesizes it for a bare
+            # prefix; only real objects carry an ETag or modification time.
+            out["is_marker"] = out["type"] == "file" or bool(etag or modified)
+            out["type"] = "directory"
+        if (
+            self.inline_threshold is not None
+            and out["type"] == "file"
+            and out["size"] <= self.inline_threshold
+        ):
+            await self._inline(path, out["size"])
+        return out
+
+    async def _inline(self, path: str, size: int) -> None:
+        """Keep a small object's content for ``cat_file`` to serve later"""
+        try:
+            data = bytes(await self._read(path, size=size))
+        except NotFound:
+            return
+        if len(self._inline_cache) >= _INLINE_CACHE_ENTRIES:
+            del self._inline_cache[next(iter(self._inline_cache))]
+        self._inline_cache[self._strip_protocol(path)] = data
+
+    async def _learn_case(self, path: str) -> bool:
+        """List the parents of path to learn its real casing
+
+        Returns True when the path now maps to a different backend key, i.e.
+        when retrying the failed operation is worthwhile.
+        """
+        if not self.case_insensitive:
+            return False
+        stripped = path.rstrip("/")
+        if not stripped:
+            return False
+
+        before = self._to_backend_path(path)
+        parent = self._parent(stripped)
+        if parent and parent.lower() not in self._case_index:
+            await self._learn_case(parent)
+        try:
+            await self._ls(parent, detail=False)
+        except (FileNotFoundError, NotFound):
+            return False
+        return self._to_backend_path(path) != before
+
+    def _info_from_cache(self, path: str) -> dict[str, Any] | None:
+        """Return the entry for path from a cached parent listing, if any"""
+        path = self._strip_protocol(path)
+        try:
+            listing = self.dircache[self._parent(path)]
+        except KeyError:
+            return None
+        for entry in listing:
+            if entry["name"] == path:
+                return entry
+        return None
+
+    def _check_key(self, path: str) -> None:
+        """Raise ``KeyTooLongError`` if path cannot be stored on the backend"""
+        if self.max_key_length is None:
+            return
+        root = str(self.storage_options.get("root") or "").strip("/")
+        key = self._to_backend_path(path).lstrip("/")
+        length = len(f"{root}/{key}".lstrip("/").encode())
+        if length > self.max_key_length:
+            raise KeyTooLongError(path, length, self.max_key_length)
 
     async def _mkdir(self, path: str, create_parents: bool = True, **kwargs) -> None:
         """Create directory"""
+        self._check_key(path.rstrip("/") + "/")
         await self.async_fs.create_dir(path)
         self.invalidate_cache(self._parent(path.rstrip("/")))
 
     async def _rmdir(self, path: str, recursive: bool = False) -> None:
         """Remove directory"""
+        if recursive and self._trashes(path):
+            await self._rm(path, recursive=True)
+            return
         if recursive:
             await self.async_fs.remove_all(path)
         else:
@@ -119,31 +1246,468 @@ class OpendalFileSystem(AsyncFileSystem):
         self.invalidate_cache(path)
         self.invalidate_cache(self._parent(base))
 
-    async def _rm_file(self, path: str, **kwargs) -> None:
-        """Remove file"""
-        await self.async_fs.delete(path)
+    async def _plan_delete(
+        self,
+        path: str | list[str],
+        recursive: bool = False,
+        batch_size: int | None = None,
+        count: bool = True,
+    ) -> DeletePlan:
+        """List what deleting path would remove and group it for execution
+
+        With ``count=False``, directories removed recursively are not listed
+        up front, leaving their entry counts out of the plan.
+        """
+        plan = DeletePlan(batch_size=batch_size or DEFAULT_DELETE_GROUP_SIZE)
+
+        for p in [path] if isinstance(path, str) else path:
+            p = self._strip_protocol(p)
+            if recursive and not has_magic(p) and await self._isdir(p):
+                prefix = p.rstrip("/") + "/"
+                plan.prefixes[prefix] = (
+                    len(await self._find(prefix, withdirs=True)) if count else None
+                )
+            else:
+                expanded = await self._expand_path(p, recursive=recursive)
+                plan.keys.extend(sorted(expanded, reverse=True))
+        return plan
+
+    async def _execute_delete(
+        self,
+        plan: DeletePlan,
+        max_concurrency: int | None = None,
+        retries: int = 0,
+        retry_budget: RetryBudget | None = None,
+    ) -> DeleteReport:
+        """Run a delete plan, collecting per-key failures instead of stopping
+
+        Prefixes go through ``remove_all`` and keys through one DELETE each,
+        see ``DeletePlan``. Each key is retried up to ``retries`` times after
+        transient errors, within ``retry_budget`` when given.
+        """
+        report = DeleteReport()
+        start = time.perf_counter()
+        budget = retry_budget if retry_budget is not None else RetryBudget()
+
+        def delete(key: str) -> Awaitable[Any]:
+            return self._call(
+                lambda: self.async_fs.delete(key), retries=retries, retry_budget=budget
+            )
+
+        for prefix, count in plan.prefixes.items():
+            report.requests += 1
+            try:
+                await self.async_fs.remove_all(prefix)
+            except Exception as err:
+                report.failed[prefix] = err
+            else:
+                report.deleted += count or 0
+            self.invalidate_cache(prefix)
+            self.invalidate_cache(self._parent(prefix.rstrip("/")))
+
+        for group in plan.groups:
+            report.requests += len(group)
+            results = await run_batch(
+                [delete(key) for key in group],
+                on_error="return",
+                limit=max_concurrency,
+            )
+            for key, result in zip(group, results):
+                if isinstance(result, BaseException):
+                    report.failed[key] = result
+                else:
+                    report.deleted += 1
+                self.invalidate_cache(self._parent(key.rstrip("/")))
+
+        report.retries = budget.retries
+        report.elapsed = time.perf_counter() - start
+        return report
+
+    plan_delete = sync_wrapper(_plan_delete)
+    execute_delete = sync_wrapper(_execute_delete)
+
+    async def _summary(self, path: str) -> TreeSummary:
+        """Aggregate sizes and modification times of the files under path
+
+        Entries are added as the recursive listing streams in, so no list of
+        them is kept. Where the backend lists sizes, no file is stat'ed.
+        """
+        listed_sizes = getattr(self.async_fs.capability(), "list_has_content_length", False)
+        path = self._strip_protocol(path).rstrip("/")
+        summary = TreeSummary()
+        try:
+            async for entry in await self._list_recursive(f"{path}/" if path else "/"):
+                name = self._from_backend_path(entry.path)
+                if name.endswith("/"):
+                    continue
+                metadata = getattr(entry, "metadata", None)
+                if listed_sizes and metadata is not None:
+                    info = await self._info_from_metadata(name, metadata)
+                else:
+                    info = await self._info(name)
+                summary.add(info.get("size") or 0, info.get("mtime"))
+        except NotFound:
+            pass
+        if not summary.files and path and await self._isfile(path):
+            info = await self._info(path)
+            summary.add(info.get("size") or 0, info.get("mtime"))
+        return summary
+
+    summary = sync_wrapper(_summary)
+
+    async def _rm(
+        self,
+        path: str | list[str],
+        recursive: bool = False,
+        batch_size: int | None = None,
+        **kwargs: Any,
+    ) -> None:
+        """Delete paths through the delete planner
+
+        Directories removed recursively go in one ``remove_all``, which uses
+        the backend's batch delete where it has one. Other keys take one
+        DELETE each, ``batch_size`` at a time, see ``plan_delete``. A key
+        failing does not stop the others; ``PartialDeleteError`` is raised
+        at the end with the report. For the report of a delete that
+        succeeds, run ``plan_delete`` and ``execute_delete`` directly.
+
+        With ``lifecycle_threshold``, directories are counted first, and
+        deleting more entries than that logs an S3 lifecycle rule that would
+        expire them server-side.
+        """
+        if self.trash_prefix is not None or kwargs.get("maxdepth") is not None:
+            if self.trash_prefix is not None:
+                # One folder per call, so a recursive rm can be restored as a unit.
+                kwargs.setdefault("trash_stamp", self._trash_stamp())
+            return await super()._rm(path, recursive=recursive, batch_size=batch_size, **kwargs)
+
+        threshold = kwargs.get("lifecycle_threshold")
+        plan = await self._plan_delete(
+            path, recursive=recursive, batch_size=batch_size, count=threshold is not None
+        )
+        report = await self._execute_delete(plan, max_concurrency=kwargs.get("max_concurrency"))
+        if threshold is not None:
+            report.suggestion = plan.lifecycle_suggestion(threshold)
+        if report.suggestion is not None:
+            logger.info(
+                "Deleted %d entries with %d requests at %.0f/s; a lifecycle rule would "
+                "expire them server-side: %s",
+                report.deleted,
+                report.requests,
+                report.items_per_second,
+                json.dumps(report.suggestion),
+            )
+        if report.failed:
+            raise PartialDeleteError(report)
+
+    async def _rm_file(self, path: str, trash_stamp: str | None = None, **kwargs) -> None:
+        """Remove file, or move it to the trash when trash mode is on"""
+        if self._trashes(path) and not await self._isdir(path):
+            target = f"{self.trash_prefix}/{trash_stamp or self._trash_stamp()}/{path.lstrip('/')}"
+            try:
+                await self.async_fs.rename(path, target)
+            except Unsupported:
+                self._emulate("rename", "copying and deleting")
+                await self._cp_file(path, target)
+                await self.async_fs.delete(path)
+            self.invalidate_cache(self.trash_prefix)
+        else:
+            await self.async_fs.delete(path)
         self.invalidate_cache(self._parent(path))
 
+    def _trashes(self, path: str) -> bool:
+        """Whether deleting path moves it to the trash instead"""
+        if self.trash_prefix is None:
+            return False
+        path = self._strip_protocol(path).strip("/")
+        return path != self.trash_prefix and not path.startswith(self.trash_prefix + "/")
+
+    @staticmethod
+    def _trash_stamp() -> str:
+        return datetime.now(timezone.utc).strftime("%Y%m%dT%H%M%S.%fZ")
+
+    async def _empty_trash(self, older_than: float | timedelta = 0) -> list[str]:
+        """Permanently delete trash folders older than ``older_than`` seconds
+
+        Returns the removed folders, one per ``rm`` call that filled them.
+        """
+        if self.trash_prefix is None:
+            raise ValueError("trash mode is not enabled, pass trash_prefix=")
+        if isinstance(older_than, timedelta):
+            older_than = older_than.total_seconds()
+        cutoff = time.time() - older_than
+
+        try:
+            folders = await self._ls(self.trash_prefix, detail=False)
+        except FileNotFoundError:
+            return []
+
+        removed = []
+        for folder in folders:
+            folder = folder.rstrip("/")
+            stamp = folder.rsplit("/", 1)[-1]
+            try:
+                moved = datetime.strptime(stamp, "%Y%m%dT%H%M%S.%fZ")
+            except ValueError:
+                continue  # not created by rm, leave it alone
+            if moved.replace(tzinfo=timezone.utc).timestamp() <= cutoff:
+                await self.async_fs.remove_all(folder + "/")
+                removed.append(folder)
+        self.invalidate_cache(self.trash_prefix)
+        return removed
+
+    empty_trash = sync_wrapper(_empty_trash)
+
     async def _cp_file(self, path1: str, path2: str, **kwargs) -> None:
-        """Copy file from path1 to path2."""
+        """Copy file from path1 to path2
+
+        Backends with native copy do it server-side; others stream the
+        object through in chunks, so it never has to fit in memory.
+        """
+        self._check_key(path2)
+        if getattr(self.async_fs.capability(), "copy", True):
+            try:
+                await self.async_fs.copy(path1, path2)
+            except Unsupported:
+                pass
+            else:
+                self.invalidate_cache(self._parent(path2.rstrip("/")))
+                return
+        self._emulate("copy", "streaming the object through the client")
+        await self._write_stream(path2, self._read_chunks(path1))
+
+    async def _read_chunks(self, path: str, chunk_size: int = _WRITE_CHUNK_SIZE):
+        """Yield the content of path ``chunk_size`` bytes at a time"""
+        reader = await self.async_fs.open(path, "rb")
         try:
-            await self.async_fs.copy(path1, path2)
-        except Unsupported:
-            data = await self.async_fs.read(path1)
-            await self.async_fs.write(path2, data)
-        self.invalidate_cache(self._parent(path2.rstrip("/")))
+            while chunk := await reader.read(chunk_size):
+                yield chunk
+        finally:
+            await reader.close()
+
+    async def _concat(
+        self, dest: str, sources: list[str], chunk_size: int = _WRITE_CHUNK_SIZE
+    ) -> int:
+        """Assemble dest from sources in order and return its size
+
+        This is client-side only: OpenDAL exposes no server-side composition
+        (S3 UploadPartCopy, GCS compose), so every part is downloaded and
+        uploaded again through one writer, a chunk at a time. When dest is the
+        first source and the backend can append, the other parts are appended
+        to it and the first is not copied at all.
+        """
+        dest = self._strip_protocol(dest)
+        sources = [self._strip_protocol(source) for source in sources]
+        if not sources:
+            raise ValueError("concat needs at least one source")
+        self._check_key(dest)
+
+        if sources[0] == dest and getattr(self.async_fs.capability(), "write_can_append", False):
+            written = (await self._info(dest))["size"]
+            for source in sources[1:]:
+                async for chunk in self._read_chunks(source, chunk_size):
+                    await self.async_fs.write(dest, bytes(chunk), append=True)
+                    written += len(chunk)
+            self.invalidate_cache(self._parent(dest))
+            return written
+
+        async def parts():
+            for source in sources:
+                async for chunk in self._read_chunks(source, chunk_size):
+                    yield chunk
+
+        if dest not in sources:
+            return await self._write_stream(dest, parts(), chunk_size)
+        return await self._write_replacing(dest, parts(), chunk_size)
+
+    concat = sync_wrapper(_concat)
 
-    async def _cat_file(self, path: str, start: int | None = None, end: int | None = None, **kwargs):
+    async def _update_metadata(
+        self,
+        path: str | list[str],
+        content_type: str | None = None,
+        metadata: dict[str, str] | None = None,
+        on_error: str = "raise",
+        max_concurrency: int | None = None,
+    ) -> list[str]:
+        """Set the content type or user metadata of existing objects
+
+        OpenDAL copies keep the source headers, so each object is streamed
+        back through the client and rewritten in place with every other
+        header it reports kept. ``metadata`` replaces the user metadata as a
+        whole; without it the existing user metadata is kept. ``path`` may be
+        a list or a glob; returns the paths that were updated.
+        """
+        if content_type is None and metadata is None:
+            raise ValueError("nothing to update, pass content_type or metadata")
+        paths = await self._expand_path(path)
+
+        async def rewrite(p: str) -> str:
+            stat = await self.async_fs.stat(p)
+            options: dict[str, Any] = {}
+            for key in _REWRITTEN_HEADERS:
+                value = getattr(stat, key, None)
+                if value:
+                    options[key] = dict(value) if key == "user_metadata" else value
+            if content_type is not None:
+                options["content_type"] = content_type
+            if metadata is not None:
+                options["user_metadata"] = dict(metadata)
+            await self._write_replacing(p, self._read_chunks(p), **options)
+            return p
+
+        files = [p for p in paths if not p.endswith("/")]
+        results = await run_batch(
+            [rewrite(p) for p in files], on_error=on_error, limit=max_concurrency
+        )
+        for p in files:
+            self.invalidate_cache(self._parent(p))
+        return [r for r in results if isinstance(r, str)]
+
+    update_metadata = sync_wrapper(_update_metadata)
+
+    async def _plan_mv_tree(self, source: str, target: str) -> MovePlan:
+        """List the keys under source and map each to its key under target
+
+        Directory markers are included; prefixes without one have nothing to
+        move and are left out.
+        """
+        source = self._strip_protocol(source).rstrip("/")
+        target = self._strip_protocol(target).rstrip("/")
+        plan = MovePlan(source, target)
+        keys = []
+        try:
+            async for entry in await self._list_recursive(f"{source}/"):
+                keys.append(self._from_backend_path(entry.path))
+        except NotFound:
+            pass
+        for path in sorted(keys):
+            relative = path[len(source) :].lstrip("/")
+            plan.pairs.append((path, f"{target}/{relative}" if target else relative))
+        return plan
+
+    async def _delete_keys(
+        self, keys: list[str], max_concurrency: int | None = None
+    ) -> dict[str, Any]:
+        """Delete files concurrently, then directory markers deepest first
+
+        Local directories can only be removed once empty, hence the order.
+        Returns the result for each key, the exception where it failed.
+        """
+        files = [key for key in keys if not key.endswith("/")]
+        results = await run_batch(
+            [self.async_fs.delete(key) for key in files],
+            on_error="return",
+            limit=max_concurrency,
+        )
+        out = dict(zip(files, results))
+        for marker in sorted((key for key in keys if key.endswith("/")), reverse=True):
+            try:
+                out[marker] = await self.async_fs.delete(marker)
+            except Exception as err:
+                out[marker] = err
+        return out
+
+    async def _mv_tree(
+        self,
+        source: str | MovePlan,
+        target: str | None = None,
+        max_concurrency: int | None = None,
+        rollback: bool = True,
+    ) -> MoveReport:
+        """Move every file under a prefix with concurrent copies and deletes
+
+        Accepts either two prefixes or a plan from ``plan_mv_tree``. Sources
+        are deleted only after all copies succeeded; failures are collected
+        in the returned report rather than raised.
+        """
+        if isinstance(source, MovePlan):
+            plan = source
+        elif target is None:
+            raise ValueError("mv_tree needs a target prefix or a MovePlan")
+        else:
+            plan = await self._plan_mv_tree(source, target)
+
+        report = MoveReport()
+        start = time.perf_counter()
+
+        copies = await run_batch(
+            [
+                self.async_fs.create_dir(dst) if src.endswith("/") else self._cp_file(src, dst)
+                for src, dst in plan.pairs
+            ],
+            on_error="return",
+            limit=max_concurrency,
+        )
+        copied = []
+        for (src, dst), result in zip(plan.pairs, copies):
+            if isinstance(result, BaseException):
+                report.failed[src] = result
+            else:
+                copied.append(dst)
+
+        if report.failed:
+            if rollback:
+                undone = await self._delete_keys(copied, max_concurrency)
+                for dst in copied:
+                    if isinstance(undone[dst], BaseException):
+                        report.failed[dst] = undone[dst]
+                    else:
+                        report.rolled_back.append(dst)
+        else:
+            sources = [src for src, _ in plan.pairs]
+            deletes = await self._delete_keys(sources, max_concurrency)
+            for src in sources:
+                if isinstance(deletes[src], BaseException):
+                    report.failed[src] = deletes[src]
+                else:
+                    report.moved.append(src)
+
+        self.invalidate_cache(plan.source)
+        self.invalidate_cache(plan.target)
+        self.invalidate_cache(self._parent(plan.target))
+        report.elapsed = time.perf_counter() - start
+        return report
+
+    plan_mv_tree = sync_wrapper(_plan_mv_tree)
+    mv_tree = sync_wrapper(_mv_tree)
+
+    async def _cat_file(
+        self, path: str, start: int | None = None, end: int | None = None, **kwargs: Any
+    ) -> bytes | memoryview:
         """Get file content as bytes (async implementation)."""
+        if self.use_mmap:
+            return self._mmap_view(path)[start:end]
+        overrides = self._pop_overrides(kwargs)
+        inlined = self._inline_cache.get(self._strip_protocol(path))
+        if inlined is not None:
+            return inlined[start:end]
+        prefetched = self._prefetched.pop((self._strip_protocol(path), start, end), None)
+        if prefetched is not None:
+            return prefetched
+        if self._disk_cache is not None and start is None and end is None:
+            read = functools.partial(self._cat_file_cached, path, **overrides)
+        else:
+            read = functools.partial(self._cat_file_direct, path, start, end, **overrides)
+        try:
+            return await read()
+        except NotFound:
+            if await self._learn_case(path):
+                return await read()
+            raise
+
+    async def _cat_file_direct(
+        self, path: str, start: int | None, end: int | None, **overrides: Any
+    ) -> bytes:
         if start is None and end is None:
-            return await self.async_fs.read(path)
+            return await self._read(path, **overrides)
 
         size = None
         if (start is not None and start < 0) or (end is not None and end < 0):
             try:
                 info = await self.async_fs.stat(path)
             except NotFound as err:
-                raise FileNotFoundError(path) from err
+                raise translate_error(err, path) from err
             size = info.content_length
 
         if start is None:
@@ -158,29 +1722,597 @@ class OpendalFileSystem(AsyncFileSystem):
             end = size + end
 
         if end is None:
-            if start == 0:
-                return await self.async_fs.read(path)
-            return await self.async_fs.read(path, offset=start)
+            return await self._read_range(path, start, **overrides)
 
         length = end - start
         if length <= 0:
             return b""
-        return await self.async_fs.read(path, offset=start, size=length)
+        return await self._read_range(path, start, length, **overrides)
+
+    async def _getitems(
+        self,
+        keys: Iterable[str],
+        on_error: str = "omit",
+        max_concurrency: int | None = None,
+    ) -> dict[str, Any]:
+        """Fetch many whole objects concurrently, keyed as given
+
+        For key/value workloads such as zarr v3 stores. ``on_error`` is
+        ``"omit"`` to leave failed keys out, ``"return"`` to map them to their
+        exception, or ``"raise"`` to raise the first error; missing keys fail
+        with ``FileNotFoundError``. ``max_concurrency`` bounds the requests in
+        flight and defaults to fsspec's ``gather_batch_size``.
+        """
+        if on_error not in ("omit", "return", "raise"):
+            raise ValueError(f"on_error must be 'omit', 'return' or 'raise', got {on_error!r}")
+        keys = list(keys)
+
+        async def get(key: str) -> bytes | memoryview:
+            path = self._strip_protocol(key)
+            try:
+                return await self._cat_file(path)
+            except NotFound as err:
+                raise translate_error(err, path) from err
+
+        values = await run_batch(
+            [get(key) for key in keys],
+            on_error="raise" if on_error == "raise" else "return",
+            limit=max_concurrency,
+        )
+        return {
+            key: value
+            for key, value in zip(keys, values)
+            if on_error != "omit" or not isinstance(value, BaseException)
+        }
+
+    getitems = sync_wrapper(_getitems)
+
+    async def _setitems(
+        self, values: Mapping[str, Any], max_concurrency: int | None = None
+    ) -> None:
+        """Write many objects concurrently, ``max_concurrency`` at a time"""
+        await run_batch(
+            [self._pipe_file(self._strip_protocol(key), value) for key, value in values.items()],
+            limit=max_concurrency,
+        )
+
+    setitems = sync_wrapper(_setitems)
+
+    async def _read_range(
+        self, path: str, offset: int, length: int | None = None, **overrides: Any
+    ) -> bytes:
+        """Fetch ``length`` bytes from ``offset`` with one ranged request
+
+        Only the requested bytes are transferred; ``length=None`` reads to the
+        end of the object.
+        """
+        if offset < 0 or (length is not None and length < 0):
+            raise ValueError(f"invalid range: offset={offset}, length={length}")
+        if length == 0:
+            return b""
+        return await self._read(path, offset=offset, size=length, **overrides)
+
+    read_range = sync_wrapper(_read_range)
 
-    async def _pipe_file(self, path: str, value: bytes, mode: str = "overwrite", **kwargs) -> None:
-        """Write bytes into file (async implementation)."""
-        if mode == "create" and await self._exists(path):
-            raise FileExistsError(path)
-        await self.async_fs.write(path, value)
+    async def _cat_ranges(
+        self,
+        paths: list[str],
+        starts: int | list[int] | None,
+        ends: int | list[int] | None,
+        max_gap: int | None = None,
+        batch_size: int | None = None,
+        on_error: str = "return",
+        **kwargs: Any,
+    ) -> list[Any]:
+        """Read many byte ranges concurrently, at most ``batch_size`` at once"""
+        start_list = starts if isinstance(starts, list) else [starts] * len(paths)
+        end_list = ends if isinstance(ends, list) else [ends] * len(paths)
+        if not len(paths) == len(start_list) == len(end_list):
+            raise ValueError("paths, starts and ends must have the same length")
+
+        return await run_batch(
+            [
+                self._cat_file(path, start, end, **kwargs)
+                for path, start, end in zip(paths, start_list, end_list)
+            ],
+            on_error="return" if on_error == "return" else "raise",
+            limit=batch_size,
+        )
+
+    async def _prefetch(
+        self,
+        items: list[str | tuple[str, int | None, int | None]],
+        max_concurrency: int | None = None,
+    ) -> dict[str, BaseException]:
+        """Fetch paths or ``(path, start, end)`` ranges ahead of ``cat_file``
+
+        Whole objects go to the disk cache when ``cache_dir`` is set; other
+        fetches are held in memory until the matching ``cat_file`` call takes
+        them, or ``invalidate_cache`` drops them. Returns the failures by
+        path; a failed prefetch only means the later read goes to the backend.
+        """
+        overrides = {"priority": "low"} if self._scheduler is not None else {}
+
+        async def fetch(item: str | tuple[str, int | None, int | None]) -> None:
+            path, start, end = (item, None, None) if isinstance(item, str) else item
+            path = self._strip_protocol(path)
+            if self._disk_cache is not None and start is None and end is None:
+                await self._cat_file_cached(path, **overrides)
+                return
+            data = await self._cat_file_direct(path, start, end, **overrides)
+            self._prefetched[(path, start, end)] = data
+
+        results = await run_batch(
+            [fetch(item) for item in items], on_error="return", limit=max_concurrency
+        )
+        return {
+            (item if isinstance(item, str) else item[0]): result
+            for item, result in zip(items, results)
+            if isinstance(result, BaseException)
+        }
+
+    def prefetch(
+        self,
+        items: list[str | tuple[str, int | None, int | None]],
+        max_concurrency: int | None = None,
+    ) -> concurrent.futures.Future[dict[str, BaseException]]:
+        """Start ``_prefetch`` in the background and return its future
+
+        ``result()`` waits for the fetches and gives the failures by path;
+        ``cancel()`` stops those still running. Async code can run
+        ``_prefetch`` as a task instead.
+        """
+        return asyncio.run_coroutine_threadsafe(
+            self._prefetch(items, max_concurrency=max_concurrency), self.loop
+        )
+
+    async def _readinto_at(self, path: str, offset: int, buffer: Any) -> int:
+        """Read into a caller-provided writable buffer starting at ``offset``
+
+        Up to ``len(buffer)`` bytes are read; the return value is the number
+        of bytes actually stored, which is smaller near the end of the file.
+        This is not zero-copy: the bindings return each read as a new
+        ``bytes`` object, which is then copied into ``buffer``.
+        """
+        view = memoryview(buffer).cast("B")
+        if view.readonly:
+            raise TypeError("readinto_at() needs a writable buffer")
+        if not view.nbytes:
+            return 0
+        data = await self._read(path, offset=offset, size=view.nbytes)
+        view[: len(data)] = data
+        return len(data)
+
+    readinto_at = sync_wrapper(_readinto_at)
+
+    async def _cat_file_cached(self, path: str, **overrides: Any) -> bytes:
+        """Serve a whole-object read from the disk cache, filling it on a miss
+
+        The ETag comes from a cached listing when there is one, otherwise
+        from a HEAD, which takes the place of the GET on a hit.
+        """
+        entry = self._info_from_cache(path)
+        etag = entry.get("etag") if entry else None
+        if not etag:
+            metadata = await self._call(lambda: self.async_fs.stat(path), **overrides)
+            etag = getattr(metadata, "etag", None)
+        if not etag:
+            # Without an ETag there is no safe way to tell versions apart.
+            return await self._cat_file_direct(path, None, None, **overrides)
+
+        cache = self._disk_cache
+        namespace = self._cache_namespace()
+        data = cache.get(namespace, path, etag)
+        if data is None:
+            data = await self._read(path, **overrides)
+            cache.put(namespace, path, etag, data)
+        return data
+
+    def _local_path(self, path: str) -> str:
+        """Map a key to its location on disk for the ``fs`` backend"""
+        root = self.storage_options.get("root", "/")
+        return os.path.join(root, self._strip_protocol(path).lstrip("/"))
+
+    def _mmap_view(self, path: str) -> memoryview:
+        """Memory-map a local file read-only and return a view over it"""
+        with open(self._local_path(path), "rb") as f:
+            if os.fstat(f.fileno()).st_size == 0:
+                return memoryview(b"")
+            # The map stays valid after the file is closed and lives as long
+            # as any view exported from it.
+            return memoryview(mmap.mmap(f.fileno(), 0, access=mmap.ACCESS_READ))
+
+    def _cache_namespace(self) -> str:
+        options = self.storage_options
+        location = [
+            f"{key}={options[key]}"
+            for key in ("bucket", "container", "endpoint", "root")
+            if key in options
+        ]
+        return ":".join([self.scheme, *location])
+
+    async def _pipe_file(self, path: str, value: Any, mode: str = "overwrite", **kwargs) -> None:
+        """Write bytes into file (async implementation).
+
+        Bytes-like values below ``multipart_threshold`` go out as one write;
+        ``mode="create"`` is a conditional write where the backend supports
+        it, instead of a HEAD followed by a PUT.
+        """
+        self._check_key(path)
+        check_exists = kwargs.pop("check_exists", self.check_exists)
+        if isinstance(value, (bytearray, memoryview)) and (
+            memoryview(value).nbytes < self.multipart_threshold
+        ):
+            value = bytes(value)
+
+        options: dict[str, Any] = {}
+        if mode == "create":
+            # Only single writes take the condition, which costs no request;
+            # streamed ones check first unless check_exists is off.
+            if isinstance(value, bytes):
+                options = self._create_options()
+            if not options and check_exists and await self._exists(path):
+                raise FileExistsError(path)
+
+        if isinstance(value, bytes):
+            try:
+                await self._call(
+                    self._write_once(path, value, options), **self._pop_overrides(kwargs)
+                )
+            except ConditionNotMatch as err:
+                raise FileExistsError(path) from err
+        else:
+            await self._write_stream(path, value)
         self.invalidate_cache(self._parent(path.rstrip("/")))
 
+    def _write_once(
+        self, path: str, value: bytes, options: dict[str, Any]
+    ) -> Callable[[], Awaitable[None]]:
+        """Write for ``_call``, whose retries skip content that already landed
+
+        Only with ``idempotent_writes``; otherwise every attempt writes. A
+        retry after an ambiguous failure, e.g. a timeout after the service
+        stored the object, first sends a HEAD: the object counts as written
+        when the content hash in its user metadata matches, or on backends
+        without user metadata, when its ETag is the MD5 of ``value`` as for
+        single PUTs on S3.
+        """
+        if not self.idempotent_writes:
+            return lambda: self.async_fs.write(path, value, **options)
+
+        digest = hashlib.sha256(value).hexdigest()
+        md5 = hashlib.md5(value, usedforsecurity=False).hexdigest()
+        if getattr(self.async_fs.capability(), "write_with_user_metadata", False):
+            options = {**options, "user_metadata": {_IDEMPOTENCY_KEY: digest}}
+        attempts = 0
+
+        async def write() -> None:
+            nonlocal attempts
+            attempts += 1
+            if attempts > 1:
+                try:
+                    metadata = await self.async_fs.stat(path)
+                except NotFound:
+                    metadata = None
+                if metadata is not None:
+                    stored = (getattr(metadata, "user_metadata", None) or {}).get(
+                        _IDEMPOTENCY_KEY
+                    )
+                    etag = (getattr(metadata, "etag", None) or "").strip('"')
+                    if stored == digest or (stored is None and etag == md5):
+                        return
+            await self.async_fs.write(path, value, **options)
+
+        return write
+
+    async def _write_stream(
+        self, path: str, source: Any, chunk_size: int = _WRITE_CHUNK_SIZE, **options: Any
+    ) -> int:
+        """Upload ``source`` through OpenDAL's writer, ``chunk_size`` at a time
+
+        ``source`` may be a buffer-protocol object, a readable file-like
+        object, an iterable or an async iterable of bytes-like chunks, so
+        objects larger than memory can be uploaded. Only one chunk is copied
+        at a time, and blocking reads run in a worker thread so the event loop
+        keeps serving other operations meanwhile. ``options`` such as
+        ``content_type`` are passed to the writer. Returns the number of bytes
+        written.
+        """
+        self._check_key(path)
+        writer = await self.async_fs.open(path, "wb", **options)
+        written = 0
+        async for chunk in _iter_chunks(source, chunk_size):
+            if chunk:
+                await writer.write(bytes(chunk))
+                written += len(chunk)
+        # Only close on success: closing commits whatever was written so far.
+        await writer.close()
+        self.invalidate_cache(self._parent(path.rstrip("/")))
+        return written
+
+    write_stream = sync_wrapper(_write_stream)
+
+    async def _write_replacing(
+        self, path: str, source: Any, chunk_size: int = _WRITE_CHUNK_SIZE, **options: Any
+    ) -> int:
+        """Like ``_write_stream``, for a ``source`` that may still be reading path
+
+        Writers that write in place (see ``durable_on_flush``) truncate path
+        when opened, so there ``source`` is written to a temporary key that is
+        then moved over path. Other writers only commit on close.
+        """
+        if not self.durable_on_flush:
+            return await self._write_stream(path, source, chunk_size, **options)
+        staging = f"{path}.tmp-{uuid.uuid4().hex}"
+        try:
+            written = await self._write_stream(staging, source, chunk_size, **options)
+            await self._mv(staging, path)
+        except BaseException:
+            with contextlib.suppress(Exception):
+                await self.async_fs.delete(staging)
+            raise
+        return written
+
+    async def _put_stream(self, path: str, source: Any, chunk_size: int = _WRITE_CHUNK_SIZE) -> int:
+        """Upload data pulled chunk by chunk from ``source``; see ``write_stream``"""
+        return await self._write_stream(path, source, chunk_size)
+
+    put_stream = sync_wrapper(_put_stream)
+
+    async def _get_file(self, rpath: str, lpath: str, **kwargs) -> None:
+        """Download a remote file to a local path
+
+        The object streams to disk a chunk at a time, written from a worker
+        thread, so it never has to fit in memory. A failed download removes
+        the partial file.
+        """
+        if rpath.endswith("/"):
+            os.makedirs(lpath, exist_ok=True)
+            return
+        inlined = self._inline_cache.get(self._strip_protocol(rpath))
+        with open(lpath, "wb") as f:
+            try:
+                if inlined is not None:
+                    f.write(inlined)
+                    return
+                async for chunk in self._read_chunks(rpath):
+                    await asyncio.to_thread(f.write, chunk)
+            except BaseException as err:
+                f.close()
+                os.unlink(lpath)
+                if isinstance(err, NotFound):
+                    raise translate_error(err, rpath) from err
+                raise
+
+    async def _put_file(self, lpath: str, rpath: str, **kwargs) -> None:
+        """Upload a local file to a remote path"""
+        if os.path.isdir(lpath):
+            # Object stores have no real directories to create.
+            return
+        with open(lpath, "rb") as f:
+            await self._write_stream(rpath, f)
+
+    async def _get(
+        self,
+        rpath: str | list[str],
+        lpath: str | list[str],
+        recursive: bool = False,
+        max_concurrency: int | None = None,
+        **kwargs: Any,
+    ) -> Any:
+        """Download files or whole prefixes, ``max_concurrency`` at a time
+
+        A recursive download lists the prefix with one recursive listing,
+        then streams the files to disk concurrently; ``max_concurrency``
+        defaults to fsspec's ``batch_size``.
+        """
+        if max_concurrency is not None:
+            kwargs["batch_size"] = max_concurrency
+        return await super()._get(rpath, lpath, recursive=recursive, **kwargs)
+
+    get = sync_wrapper(_get)
+
+    async def _put(
+        self,
+        lpath: str | list[str],
+        rpath: str | list[str],
+        recursive: bool = False,
+        max_concurrency: int | None = None,
+        **kwargs: Any,
+    ) -> Any:
+        """Upload files or whole directories, ``max_concurrency`` at a time"""
+        if max_concurrency is not None:
+            kwargs["batch_size"] = max_concurrency
+        return await super()._put(lpath, rpath, recursive=recursive, **kwargs)
+
+    put = sync_wrapper(_put)
+
     async def _opendal_rename(self, source: str, target: str) -> None:
         await self.async_fs.rename(source, target)
 
+    async def _presign(
+        self, path: str, expiration: int = 100, method: str = "GET"
+    ) -> SignedRequest:
+        """Presign a request on path valid for ``expiration`` seconds
+
+        Lets browsers and other clients without credentials fetch or upload
+        an object directly. Raises ``CapabilityError`` on backends that
+        cannot presign, such as the local filesystem.
+        """
+        method = method.upper()
+        if method not in PRESIGN_METHODS:
+            raise ValueError(f"method must be one of {sorted(PRESIGN_METHODS)}, got {method!r}")
+        if not getattr(self.async_fs.capability(), "presign", False):
+            raise CapabilityError(self.scheme, "presign")
+        presign = getattr(self.async_fs, PRESIGN_METHODS[method])
+        try:
+            request = await presign(self._strip_protocol(path), int(expiration))
+        except Unsupported as err:
+            raise CapabilityError(self.scheme, PRESIGN_METHODS[method]) from err
+        return SignedRequest(request.method, request.url, dict(request.headers))
+
+    presign = sync_wrapper(_presign)
+
+    def sign(self, path: str, expiration: int = 100, method: str = "GET", **kwargs: Any) -> str:
+        """Presigned URL for path, as fsspec defines it; see ``presign`` for headers"""
+        return self.presign(path, expiration=expiration, method=method).url
+
+    async def _bucket_exists(self) -> bool:
+        """Check that the configured bucket or container can be listed"""
+        try:
+            lister = await self.async_fs.list("/")
+            async for _ in lister:
+                break
+        except NotFound:
+            return False
+        except PermissionDenied as err:
+            raise translate_error(err, "/") from err
+        return True
+
+    async def _diagnose(self, timeout: float = 10.0) -> DiagnosticReport:
+        """Check DNS, TLS, credentials and list permission for this backend"""
+        return await run_diagnostics(self, timeout=timeout)
+
+    diagnose = sync_wrapper(_diagnose)
+
     # Higher-level async operations built on core methods
-    async def _exists(self, path: str, **kwargs):
-        """Check path existence"""
-        return await self.async_fs.exists(path)
+    async def _exists(
+        self, path: str, timeout: float | None = None, **kwargs: Any
+    ) -> bool | None:
+        """Check path existence
+
+        Only a missing path maps to ``False``; permission and other backend
+        errors are raised so that credential problems are not hidden. With
+        ``timeout``, gives up after that many seconds and returns ``None``
+        (unknown) rather than blocking, e.g. for interactive file browsers.
+        """
+        if timeout is not None:
+            try:
+                return await asyncio.wait_for(self._exists(path, **kwargs), timeout)
+            except TimeoutError:
+                return None
+        if not path.strip("/"):
+            return await self._bucket_exists()
+        try:
+            await self.async_fs.stat(path)
+        except NotFound:
+            if await self._learn_case(path):
+                return await self._exists(path, **kwargs)
+            return False
+        except PermissionDenied as err:
+            raise translate_error(err, path) from err
+        return True
+
+    def exists(self, path: str, timeout: float | None = None, **kwargs: Any) -> bool | None:
+        # fsspec's sync() takes ``timeout`` for itself and raises on expiry.
+        return sync(self.loop, functools.partial(self._exists, path, timeout=timeout, **kwargs))
+
+    async def _stat_mode(self, path: str) -> Any:
+        """Mode of path from a single stat, or None when it is missing"""
+        try:
+            return (await self.async_fs.stat(path)).mode
+        except NotFound:
+            if await self._learn_case(path):
+                return await self._stat_mode(path)
+            return None
+        except PermissionDenied as err:
+            raise translate_error(err, path) from err
+
+    async def _isdir(self, path: str) -> bool:
+        """Whether path is a directory, from one stat of ``path/``
+
+        Object stores only know a prefix as a directory when asked with the
+        trailing slash, and skipping ``info()`` avoids its extra reads.
+        """
+        if not path.strip("/"):
+            return await self._bucket_exists()
+        mode = await self._stat_mode(path.rstrip("/") + "/")
+        return mode is not None and mode.is_dir()
+
+    async def _isfile(self, path: str) -> bool:
+        """Whether path is a file, from one stat"""
+        if not path.strip("/") or path.endswith("/"):
+            return False
+        mode = await self._stat_mode(path)
+        return mode is not None and not mode.is_dir()
+
+    async def _glob(
+        self, path: str, maxdepth: int | None = None, **kwargs: Any
+    ) -> list[str] | dict[str, dict[str, Any]]:
+        """Glob with ``{a,b}`` brace expansion on top of fsspec's pattern rules"""
+        patterns = expand_braces(path) if isinstance(path, str) else [path]
+        if len(patterns) == 1:
+            return await self._glob_one(patterns[0], maxdepth=maxdepth, **kwargs)
+
+        results = []
+        for pattern in patterns:
+            results.append(self._glob_one(pattern, maxdepth=maxdepth, **kwargs))
+        results = await asyncio.gather(*results)
+
+        if kwargs.get("detail", False):
+            merged: dict[str, Any] = {}
+            for result in results:
+                merged.update(result)
+            return {p: merged[p] for p in sorted(merged)}
+        return sorted({p for result in results for p in result})
+
+    async def _glob_one(
+        self, pattern: Any, maxdepth: int | None = None, **kwargs: Any
+    ) -> list[str] | dict[str, dict[str, Any]]:
+        """Glob one pattern, listing only the directory its magic is in
+
+        fsspec lists the pattern's literal prefix recursively, which pulls a
+        whole subtree when only its first level can match, as in
+        ``logs/2024-*.gz``. Patterns with magic in one path segment are
+        matched against a single listing of that directory instead.
+        """
+        if not isinstance(pattern, str) or maxdepth is not None or "**" in pattern:
+            return await super()._glob(pattern, maxdepth=maxdepth, **kwargs)
+        stripped = self._strip_protocol(pattern)
+        magic = re.search(r"[*?\[]", stripped)
+        if magic is None or stripped.endswith("/"):
+            return await super()._glob(pattern, maxdepth=maxdepth, **kwargs)
+        root = stripped[: magic.start()].rpartition("/")[0]
+        if "/" in stripped[len(root) :].lstrip("/"):
+            return await super()._glob(pattern, maxdepth=maxdepth, **kwargs)
+
+        detail = kwargs.pop("detail", False)
+        try:
+            entries = await self._ls(root, detail=True, **kwargs)
+        except FileNotFoundError:
+            entries = []
+        regex = re.compile(glob_translate(stripped))
+        matched = {}
+        for info in entries:
+            name = info["name"].rstrip("/")
+            if regex.match(name):
+                matched[name] = {**info, "name": name}
+        if detail:
+            return {name: matched[name] for name in sorted(matched)}
+        return sorted(matched)
+
+    async def _ls_regex(
+        self,
+        path: str,
+        pattern: str | re.Pattern[str],
+        recursive: bool = True,
+        detail: bool = False,
+        **kwargs: Any,
+    ) -> list[str] | list[dict[str, Any]]:
+        """List entries under path whose full name matches a regular expression"""
+        regex = re.compile(pattern) if isinstance(pattern, str) else pattern
+        if recursive:
+            found = await self._find(path, withdirs=True, detail=True, **kwargs)
+            infos = list(found.values())
+        else:
+            infos = await self._ls(path, detail=True, **kwargs)
+
+        matched = [info for info in infos if regex.search(info["name"])]
+        if detail:
+            return matched
+        return [info["name"] for info in matched]
+
+    ls_regex = sync_wrapper(_ls_regex)
 
     def _open(
         self,
@@ -189,77 +2321,275 @@ class OpendalFileSystem(AsyncFileSystem):
         block_size=None,
         autocommit=True,
         cache_options=None,
+        cache_type="readahead",
         **kwargs: Any,
     ) -> OpendalBufferedFile:
-        """Open a file for reading or writing"""
+        """Open a file for reading or writing
+
+        The positional order matches ``AbstractFileSystem._open`` and
+        ``cache_type`` is accepted explicitly, as caching wrappers such as
+        ``blockcache::`` pass ``cache_type="none"``.
+        """
+        if mode != "rb":
+            self._check_key(path)
+        kwargs.setdefault("check_exists", self.check_exists)
+        if mode in ("rb", "ab") and "size" not in kwargs and "details" not in kwargs:
+            details = self._info_from_cache(path)
+            if details is not None:
+                kwargs["details"] = details
+        if mode == "rb" and self.use_mmap:
+            # Views from the map are handed out directly; caching would copy.
+            cache_type = "none"
+        if mode == "rb":
+            kwargs.setdefault("readahead", self.readahead)
         return OpendalBufferedFile(
             self,
             path,
             mode,
             block_size,
             autocommit,
+            cache_type=cache_type,
             cache_options=cache_options,
             **kwargs,
         )
 
-    async def open_async(self, path, mode="rb", **kwargs):
+    async def open_async(
+        self, path: str, mode: str = "rb", **kwargs: Any
+    ) -> OpendalAsyncBufferedFile:
         if "b" not in mode or kwargs.get("compression"):
             raise ValueError
+        if mode != "rb":
+            self._check_key(path)
 
-        size = None
-        if mode == "rb":
-            try:
-                info = await self.async_fs.stat(path)
-            except NotFound as err:
-                raise FileNotFoundError(path) from err
-            else:
-                size = info.content_length
+        check_exists = kwargs.setdefault("check_exists", self.check_exists)
+        details = None
+        if mode in ("rb", "ab"):
+            details = self._info_from_cache(path)
+            if details is None and (mode == "rb" or check_exists):
+                try:
+                    details = await self._info(path)
+                except FileNotFoundError:
+                    if mode == "rb":
+                        raise
 
-        file = OpendalAsyncBufferedFile(self, path, mode, size=size, **kwargs)
+        file = OpendalAsyncBufferedFile(self, path, mode, details=details, **kwargs)
 
         if mode == "ab":
-            try:
-                info = await self.async_fs.stat(path)
-                file.loc = info.content_length
-            except NotFound:
-                file.loc = 0
+            file.loc = details["size"] if details is not None else 0
 
         return file
 
-    async def _modified(self, path: str):
-        """Get modified time (async version)"""
+    async def _has_changed(self, path: str, token: str | None) -> tuple[bool, str | None]:
+        """Compare path with a token from an earlier call using one HEAD
+
+        Returns whether the object changed and a token for the next call.
+        The token records ETag, size and mtime; ETags are compared where both
+        sides have one, mtimes otherwise. A missing object has token ``None``,
+        so deletion and creation count as changes.
+        """
+        try:
+            info = await self._info(path)
+        except FileNotFoundError:
+            return token is not None, None
+        current = [info.get("etag"), info["size"], info.get("mtime")]
+        new_token = json.dumps(current)
+        if token is None:
+            return True, new_token
+        etag, size, mtime = json.loads(token)
+        if etag and current[0]:
+            return (etag, size) != (current[0], current[1]), new_token
+        return (size, mtime) != (current[1], current[2]), new_token
+
+    has_changed = sync_wrapper(_has_changed)
+
+    def _marker_path(self, prefix: str, marker: str) -> str:
+        return f"{self._strip_protocol(prefix).rstrip('/')}/{marker}"
+
+    async def _mark_success(
+        self, prefix: str, marker: str = _SUCCESS_MARKER, content: bytes = b""
+    ) -> None:
+        """Write the marker file announcing that the data under prefix is complete"""
+        await self._pipe_file(self._marker_path(prefix, marker), content)
+
+    mark_success = sync_wrapper(_mark_success)
+
+    async def _marker_info(self, path: str) -> dict[str, Any] | None:
+        # Straight to the backend: a cached listing may predate the marker.
+        try:
+            metadata = await self.async_fs.stat(path)
+        except NotFound:
+            return None
+        return await self._info_from_metadata(path, metadata)
+
+    async def _is_complete(self, prefix: str, marker: str = _SUCCESS_MARKER) -> bool:
+        """Whether the marker file under prefix exists, checked with one HEAD"""
+        return await self._marker_info(self._marker_path(prefix, marker)) is not None
+
+    is_complete = sync_wrapper(_is_complete)
+
+    async def _wait_for_marker(
+        self,
+        prefix: str,
+        timeout: float | None = None,
+        interval: float = 5.0,
+        marker: str = _SUCCESS_MARKER,
+    ) -> dict[str, Any]:
+        """Wait until the marker file under prefix exists and return its info
+
+        Polls with one HEAD at a time, starting fast and backing off to one
+        every ``interval`` seconds. Raises ``TimeoutError`` after ``timeout``
+        seconds.
+        """
+        path = self._marker_path(prefix, marker)
+        deadline = None if timeout is None else time.monotonic() + timeout
+        delay = min(0.1, interval)
+        while (info := await self._marker_info(path)) is None:
+            if deadline is not None:
+                remaining = deadline - time.monotonic()
+                if remaining <= 0:
+                    raise TimeoutError(f"no {marker} under {prefix} after {timeout}s")
+                delay = min(delay, remaining)
+            await asyncio.sleep(delay)
+            delay = min(delay * 2, interval)
+        return info
+
+    wait_for_marker = sync_wrapper(_wait_for_marker)
+
+    async def _checksum(self, path: str) -> int:
+        """Integer identifying the current content of path, without reading it
+
+        Derived from the backend's Content-MD5 where it reports one, else from
+        the ETag; only backends with neither fall back to fsspec's hash of the
+        ``info``. The raw values are the ``content_md5`` and ``etag`` of
+        ``info()``.
+        """
+        info = await self._info(path)
+        return int(tokenize(info.get("content_md5") or info.get("etag") or info), 16)
+
+    checksum = sync_wrapper(_checksum)
+
+    async def _modified(self, path: str) -> datetime:
+        """Get modified time (async version), with sub-second precision"""
         try:
             info = await self.async_fs.stat(path)
         except NotFound as err:
-            raise FileNotFoundError(path) from err
-        else:
-            return info.last_modified
+            raise translate_error(err, path) from err
+        if info.last_modified is None:
+            raise NotImplementedError(f"{self.scheme} does not report modification times")
+        return info.last_modified
 
-    def mv(self, path1, path2, recursive: bool = False, maxdepth: int | None = None, **kwargs):
-        if (
+    modified = sync_wrapper(_modified)
+
+    async def _created(self, path: str) -> datetime:
+        """Get creation time (async version), where the backend records one
+
+        Only some services (fs, azblob) keep a creation time apart from the
+        modification time, and only bindings exposing it as ``created`` on the
+        metadata can report it; everything else raises ``NotImplementedError``.
+        """
+        try:
+            info = await self.async_fs.stat(path)
+        except NotFound as err:
+            raise translate_error(err, path) from err
+        created = getattr(info, "created", None)
+        if created is None:
+            raise NotImplementedError(f"{self.scheme} does not report creation times")
+        return created
+
+    created = sync_wrapper(_created)
+
+    async def _mv(
+        self,
+        path1: str | list[str],
+        path2: str | list[str],
+        recursive: bool = False,
+        maxdepth: int | None = None,
+        **kwargs: Any,
+    ) -> None:
+        """Move files, renaming them server-side where the backend can
+
+        A recursive move of a directory renames each file under it
+        concurrently and recreates its directory markers; a failure part way
+        leaves the rest in place, so use ``mv_tree`` for an all-or-nothing
+        move. Backends without rename fall back to copy and delete, deleting
+        only once every copy succeeded.
+        """
+        if self._strip_protocol(path1) == self._strip_protocol(path2):
+            logger.debug("mv: %s and %s are the same path, nothing to move", path1, path2)
+            return None
+        if not getattr(self.async_fs.capability(), "rename", True):
+            self._emulate("rename", "copying and deleting")
+        elif (
             isinstance(path1, str)
             and isinstance(path2, str)
-            and not recursive
             and maxdepth is None
             and not has_magic(path1)
         ):
-            src = self._strip_protocol(path1)
+            src = self._strip_protocol(path1).rstrip("/")
             dst = self._strip_protocol(path2)
-            if trailing_sep(dst) or self.isdir(dst):
-                base = src.rstrip("/").split("/")[-1]
-                dst = dst.rstrip("/") + "/" + base
+            if trailing_sep(dst) or await self._isdir(dst):
+                dst = dst.rstrip("/") + "/" + src.split("/")[-1]
+            dst = dst.rstrip("/")
             try:
-                self.operator.rename(src, dst)
-                self.invalidate_cache(self._parent(src.rstrip("/")))
-                self.invalidate_cache(self._parent(dst.rstrip("/")))
+                if recursive and await self._isdir(src):
+                    plan = await self._plan_mv_tree(src, dst)
+                    await run_batch([self.async_fs.rename(old, new) for old, new in plan.files])
+                    await run_batch([self.async_fs.create_dir(new) for _, new in plan.markers])
+                    deleted = await self._delete_keys([old for old, _ in plan.markers])
+                    for result in deleted.values():
+                        if isinstance(result, BaseException):
+                            raise result
+                    self.invalidate_cache(src)
+                    self.invalidate_cache(dst)
+                else:
+                    await self.async_fs.rename(src, dst)
+                self.invalidate_cache(self._parent(src))
+                self.invalidate_cache(self._parent(dst))
                 return None
             except Unsupported:
-                pass
-        return super().mv(path1, path2, recursive=recursive, maxdepth=maxdepth, **kwargs)
+                self._emulate("rename", "copying and deleting")
+        # Without on_error="raise", recursive copies skip missing sources,
+        # whose originals would then be deleted below.
+        kwargs["on_error"] = "raise"
+        await self._copy(path1, path2, recursive=recursive, maxdepth=maxdepth, **kwargs)
+        await self._rm(path1, recursive=recursive)
+        return None
+
+    mv = sync_wrapper(_mv)
+
+    def mirror_to_local(
+        self,
+        prefix: str,
+        local_dir: str | os.PathLike[str],
+        watch: bool = False,
+        interval: float = 30.0,
+    ) -> LocalMirror:
+        """Materialize prefix in local_dir, for tools that need real paths
 
-    def invalidate_cache(self, path: str | None = None):
+        With ``watch``, the copy is refreshed every ``interval`` seconds in
+        the background; stop it with the returned mirror's ``stop()``.
+        """
+        mirror = LocalMirror(self, prefix, local_dir, interval=interval)
+        mirror.refresh()
+        if watch:
+            mirror.start()
+        return mirror
+
+    def get_mapper(
+        self,
+        root: str = "",
+        check: bool = False,
+        create: bool = False,
+        missing_exceptions: tuple[type[BaseException], ...] | None = None,
+    ) -> OpendalMapper:
+        """Key/value store over root with batched access, e.g. for zarr"""
+        return OpendalMapper(root, self, check, create, missing_exceptions)
+
+    def invalidate_cache(self, path: str | None = None) -> None:
         if path is None:
             self.dircache.clear()
+            self._inline_cache.clear()
+            self._prefetched.clear()
             super().invalidate_cache(path)
             return
 
@@ -269,4 +2599,13 @@ class OpendalFileSystem(AsyncFileSystem):
         for key in list(self.dircache):
             if key.startswith(prefix):
                 self.dircache.pop(key, None)
+        for key in list(self._inline_cache):
+            if not stripped or key == stripped or key.startswith(prefix):
+                self._inline_cache.pop(key, None)
+        for entry in list(self._prefetched):
+            if not stripped or entry[0] == stripped or entry[0].startswith(prefix):
+                self._prefetched.pop(entry, None)
         super().invalidate_cache(stripped)
+
+
+translate_handled_errors(OpendalFileSystem)
@@ -0,0 +1,39 @@
+from __future__ import annotations
+
+
+def expand_braces(pattern: str) -> list[str]:
+    """Expand ``{a,b}`` alternations into the list of plain glob patterns.
+
+    Nested groups are expanded recursively and the first-seen order is kept.
+    Groups without a top-level comma (``{a}``) and unbalanced braces are left
+    as literal text, mirroring shell brace expansion.
+    """
+    start = pattern.find("{")
+    while start != -1:
+        depth = 0
+        end = -1
+        commas: list[int] = []
+        for i in range(start, len(pattern)):
+            char = pattern[i]
+            if char == "{":
+                depth += 1
+            elif char == "}":
+                depth -= 1
+                if depth == 0:
+                    end = i
+                    break
+            elif char == "," and depth == 1:
+                commas.append(i)
+
+        if end != -1 and commas:
+            prefix, suffix = pattern[:start], pattern[end + 1 :]
+            bounds = [start, *commas, end]
+            out: list[str] = []
+            for lo, hi in zip(bounds, bounds[1:]):
+                for expanded in expand_braces(prefix + pattern[lo + 1 : hi] + suffix):
+                    if expanded not in out:
+                        out.append(expanded)
+            return out
+
+        start = pattern.find("{", start + 1)
+    return [pattern]
@@ -0,0 +1,83 @@
+from __future__ import annotations
+
+import asyncio
+import concurrent.futures
+import time
+from collections.abc import Awaitable, Callable, Sequence
+from dataclasses import asdict, dataclass, field
+from typing import TYPE_CHECKING, Any
+
+from .diagnostics import _authenticate, _list_root
+
+if TYPE_CHECKING:
+    from .fs import OpendalFileSystem
+
+
+@dataclass
+class HealthStatus:
+    """Status of one filesystem for a status page.
+
+    ``latency`` maps each probe (``stat``, ``list``) to its duration in
+    seconds; a failed probe is missing from it and described in ``error``.
+    ``capabilities`` lists the operations the backend supports.
+    """
+
+    scheme: str
+    reachable: bool
+    latency: dict[str, float] = field(default_factory=dict)
+    capabilities: list[str] = field(default_factory=list)
+    error: str | None = None
+
+    def to_dict(self) -> dict[str, Any]:
+        return asdict(self)
+
+
+def _capabilities(fs: OpendalFileSystem) -> list[str]:
+    cap = fs.operator.capability()
+    return sorted(
+        name
+        for name in dir(cap)
+        if not name.startswith("_") and getattr(cap, name) is True
+    )
+
+
+async def _probe(fs: OpendalFileSystem, timeout: float) -> HealthStatus:
+    status = HealthStatus(fs.scheme, reachable=False)
+    try:
+        status.capabilities = _capabilities(fs)
+    except Exception:
+        pass
+
+    probes: list[tuple[str, Callable[[], Awaitable[str]]]] = [
+        ("stat", lambda: _authenticate(fs)),
+        ("list", lambda: _list_root(fs)),
+    ]
+    for name, probe in probes:
+        start = time.perf_counter()
+        try:
+            await asyncio.wait_for(probe(), timeout)
+        except asyncio.TimeoutError:
+            status.error = f"{name} timed out after {timeout}s"
+            return status
+        except Exception as err:
+            status.error = f"{name}: {type(err).__name__}: {err}"
+            return status
+        status.latency[name] = time.perf_counter() - start
+    status.reachable = True
+    return status
+
+
+def health(
+    filesystems: Sequence[OpendalFileSystem], timeout: float = 5.0
+) -> list[HealthStatus]:
+    """Probe several filesystems concurrently, one status per filesystem
+
+    Each probe is a HEAD on a random key and a one-entry listing of the root,
+    each limited to ``timeout`` seconds, so a slow backend delays the result
+    by at most twice that.
+    """
+    futures = [
+        asyncio.run_coroutine_threadsafe(_probe(fs, timeout), fs.loop) for fs in filesystems
+    ]
+    concurrent.futures.wait(futures)
+    return [future.result() for future in futures]
@@ -0,0 +1,100 @@
+from __future__ import annotations
+
+from collections.abc import Iterable, Mapping
+from typing import TYPE_CHECKING, Any
+
+from fsspec.asyn import sync
+from fsspec.mapping import FSMap, maybe_convert
+from opendal.exceptions import NotFound
+
+from .batch import run_batch
+
+if TYPE_CHECKING:
+    from .fs import OpendalFileSystem
+
+
+class OpendalMapper(FSMap):
+    """Key/value view of a prefix, served straight by the operator.
+
+    A drop-in ``FSMap`` for zarr and xarray chunk IO. ``getitems``,
+    ``setitems``, ``delitems`` and ``contains_many`` each run as one
+    concurrent batch on the filesystem's loop; setting a key skips the
+    ``mkdirs`` FSMap sends first, and membership is one HEAD rather than an
+    ``isfile`` that may fall back to listing the parent.
+    """
+
+    fs: OpendalFileSystem
+
+    def _missing(self) -> tuple[type[BaseException], ...]:
+        return (NotFound, *self.missing_exceptions)
+
+    async def _get(self, path: str) -> bytes:
+        try:
+            return bytes(await self.fs._cat_file(path))
+        except self._missing() as err:
+            raise KeyError(path) from err
+
+    async def _contains(self, path: str) -> bool:
+        try:
+            info = await self.fs.async_fs.stat(path)
+        except NotFound:
+            return False
+        return not info.mode.is_dir()
+
+    def getitems(self, keys: Iterable[Any], on_error: str = "raise") -> dict[Any, Any]:
+        """Fetch many values at once
+
+        ``on_error`` is ``"raise"`` to raise ``KeyError`` for the first
+        missing key, ``"omit"`` to leave missing keys out, or ``"return"`` to
+        map them to their exception.
+        """
+        keys = list(keys)
+        paths = [self._key_to_str(key) for key in keys]
+        try:
+            values = self.fs.getitems(paths, on_error="raise" if on_error == "raise" else "return")
+        except self.missing_exceptions as err:
+            raise KeyError(str(err)) from err
+
+        out = {}
+        for key, path in zip(keys, paths):
+            value = values[path]
+            if isinstance(value, self.missing_exceptions):
+                value = KeyError(key)
+            if isinstance(value, BaseException) and on_error == "omit":
+                continue
+            out[key] = bytes(value) if isinstance(value, memoryview) else value
+        return out
+
+    def setitems(self, values_dict: Mapping[Any, Any]) -> None:
+        """Write many values at once"""
+        self.fs.setitems(
+            {self._key_to_str(key): maybe_convert(value) for key, value in values_dict.items()}
+        )
+
+    def delitems(self, keys: Iterable[Any]) -> None:
+        """Delete many keys at once, in the backend's batch requests"""
+        paths = [self._key_to_str(key) for key in keys]
+        if paths:
+            self.fs.rm(paths)
+
+    async def _contains_many(self, keys: list[Any]) -> dict[Any, bool]:
+        found = await run_batch([self._contains(self._key_to_str(key)) for key in keys])
+        return dict(zip(keys, found))
+
+    def contains_many(self, keys: Iterable[Any]) -> dict[Any, bool]:
+        """Check many keys at once, e.g. which chunks of an array are written"""
+        return sync(self.fs.loop, self._contains_many, list(keys))
+
+    def __getitem__(self, key: Any, default: Any = None) -> bytes:
+        try:
+            return sync(self.fs.loop, self._get, self._key_to_str(key))
+        except KeyError:
+            if default is not None:
+                return default
+            raise KeyError(key) from None
+
+    def __setitem__(self, key: Any, value: Any) -> None:
+        self.fs.pipe_file(self._key_to_str(key), maybe_convert(value))
+
+    def __contains__(self, key: Any) -> bool:
+        return sync(self.fs.loop, self._contains, self._key_to_str(key))
@@ -0,0 +1,85 @@
+from __future__ import annotations
+
+import threading
+from dataclasses import dataclass, replace
+
+
+@dataclass(frozen=True)
+class ReadTiming:
+    """Timing of a single read issued to the backend.
+
+    The OpenDAL bindings return a read as one buffer, so ``elapsed`` covers
+    the whole request; time to first byte is not observable from here.
+    """
+
+    path: str
+    offset: int
+    size: int | None
+    nbytes: int
+    elapsed: float
+
+    @property
+    def throughput(self) -> float:
+        """Bytes per second for this read"""
+        return self.nbytes / self.elapsed if self.elapsed else 0.0
+
+
+@dataclass(frozen=True)
+class RuntimeStats:
+    """Snapshot of the work a filesystem has in flight.
+
+    ``inflight`` counts backend calls running on either operator and
+    ``loop_tasks`` the tasks alive on the event loop serving the sync API.
+    ``slots_in_use`` and ``queued`` describe ``priority_slots`` and are zero
+    without it; ``worker_threads`` and ``worker_queue`` describe the
+    ``worker_threads`` pool and are zero without it.
+    """
+
+    inflight: int
+    loop_tasks: int
+    slots_in_use: int
+    queued: int
+    worker_threads: int
+    worker_queue: int
+
+
+@dataclass
+class OperationStats:
+    """Cumulative counters for one kind of backend call.
+
+    ``bytes`` counts payload read or written, ``elapsed`` the seconds spent
+    in calls, failed ones included.
+    """
+
+    requests: int = 0
+    errors: int = 0
+    bytes: int = 0
+    elapsed: float = 0.0
+
+    @property
+    def throughput(self) -> float:
+        """Bytes per second spent in calls"""
+        return self.bytes / self.elapsed if self.elapsed else 0.0
+
+
+class StatsRecorder:
+    """Thread-safe ``OperationStats`` per operation name"""
+
+    def __init__(self) -> None:
+        self._lock = threading.Lock()
+        self._stats: dict[str, OperationStats] = {}
+
+    def record(self, operation: str, elapsed: float, nbytes: int = 0, failed: bool = False) -> None:
+        with self._lock:
+            stats = self._stats.setdefault(operation, OperationStats())
+            stats.requests += 1
+            stats.errors += failed
+            stats.bytes += nbytes
+            stats.elapsed += elapsed
+
+    def snapshot(self, reset: bool = False) -> dict[str, OperationStats]:
+        with self._lock:
+            out = {name: replace(stats) for name, stats in sorted(self._stats.items())}
+            if reset:
+                self._stats.clear()
+        return out
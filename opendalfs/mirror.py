@@ -0,0 +1,109 @@
+from __future__ import annotations
+
+import contextlib
+import logging
+import os
+import threading
+from typing import TYPE_CHECKING, Any
+
+if TYPE_CHECKING:
+    from .fs import OpendalFileSystem
+
+logger = logging.getLogger("opendalfs")
+
+
+class LocalMirror:
+    """Local directory holding a copy of the files under a prefix.
+
+    ``refresh()`` downloads new and changed files and removes local copies
+    of deleted ones; files are replaced atomically, so readers never see a
+    partial download. ``start()`` refreshes every ``interval`` seconds on a
+    daemon thread until ``stop()``. Changes are detected by ETag, size and
+    modification time; local edits are overwritten.
+    """
+
+    def __init__(
+        self,
+        fs: OpendalFileSystem,
+        prefix: str,
+        local_dir: str | os.PathLike[str],
+        interval: float = 30.0,
+    ) -> None:
+        self.fs = fs
+        self.prefix = fs._strip_protocol(prefix).rstrip("/")
+        self.local_dir = os.path.abspath(local_dir)
+        self.interval = interval
+        self._versions: dict[str, tuple[Any, ...]] = {}
+        self._stop = threading.Event()
+        self._thread: threading.Thread | None = None
+
+    def local_path(self, key: str) -> str | None:
+        """Where key is mirrored, or ``None`` if it would escape the directory"""
+        relative = key[len(self.prefix) :].lstrip("/") if self.prefix else key
+        target = os.path.normpath(os.path.join(self.local_dir, relative))
+        if target == self.local_dir or os.path.commonpath([self.local_dir, target]) != self.local_dir:
+            return None
+        return target
+
+    def refresh(self) -> list[str]:
+        """Bring the directory up to date and return the keys downloaded"""
+        self.fs.invalidate_cache(self.prefix)
+        listing = self.fs.find(self.prefix, detail=True)
+        os.makedirs(self.local_dir, exist_ok=True)
+
+        updated: list[str] = []
+        current: dict[str, tuple[Any, ...]] = {}
+        for key, info in listing.items():
+            if info.get("type", "file") != "file":
+                continue
+            target = self.local_path(key)
+            if target is None:
+                logger.warning("Not mirroring %s outside %s", key, self.local_dir)
+                continue
+            version = (info.get("etag"), info.get("size"), info.get("mtime"))
+            current[key] = version
+            if self._versions.get(key) == version and os.path.exists(target):
+                continue
+            os.makedirs(os.path.dirname(target), exist_ok=True)
+            partial = f"{target}.opendalfs-partial"
+            self.fs.get_file(key, partial)
+            os.replace(partial, target)
+            updated.append(key)
+
+        for key in self._versions.keys() - current.keys():
+            target = self.local_path(key)
+            if target is not None:
+                with contextlib.suppress(FileNotFoundError):
+                    os.remove(target)
+        self._versions = current
+        return updated
+
+    def start(self) -> LocalMirror:
+        """Keep refreshing in the background until ``stop()``"""
+        if self._thread is None:
+            self._stop.clear()
+            self._thread = threading.Thread(
+                target=self._watch, name="opendalfs-mirror", daemon=True
+            )
+            self._thread.start()
+        return self
+
+    def stop(self) -> None:
+        self._stop.set()
+        if self._thread is not None:
+            self._thread.join()
+            self._thread = None
+
+    def _watch(self) -> None:
+        while not self._stop.wait(self.interval):
+            try:
+                self.refresh()
+            except Exception:
+                # A transient outage should not end the watch.
+                logger.warning("Refreshing mirror of %s failed", self.prefix, exc_info=True)
+
+    def __enter__(self) -> LocalMirror:
+        return self
+
+    def __exit__(self, *exc_info: Any) -> None:
+        self.stop()
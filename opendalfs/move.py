@@ -0,0 +1,43 @@
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+
+
+@dataclass
+class MovePlan:
+    """Source and destination key pairs for moving a prefix
+
+    Directory markers, the keys ending with a slash, are moved along with
+    the files so that empty directories survive the move.
+    """
+
+    source: str
+    target: str
+    pairs: list[tuple[str, str]] = field(default_factory=list)
+
+    @property
+    def files(self) -> list[tuple[str, str]]:
+        return [pair for pair in self.pairs if not pair[0].endswith("/")]
+
+    @property
+    def markers(self) -> list[tuple[str, str]]:
+        return [pair for pair in self.pairs if pair[0].endswith("/")]
+
+
+@dataclass
+class MoveReport:
+    """Outcome of executing a ``MovePlan``
+
+    Keys are only deleted from the source once every copy succeeded. When a
+    copy fails, the copies already made are removed again and listed in
+    ``rolled_back``, leaving the source as it was.
+    """
+
+    moved: list[str] = field(default_factory=list)
+    failed: dict[str, BaseException] = field(default_factory=dict)
+    rolled_back: list[str] = field(default_factory=list)
+    elapsed: float = 0.0
+
+    @property
+    def ok(self) -> bool:
+        return not self.failed
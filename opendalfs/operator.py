@@ -0,0 +1,414 @@
+from __future__ import annotations
+
+import asyncio
+import contextlib
+import functools
+import inspect
+import logging
+import threading
+import time
+from collections.abc import AsyncIterator, Callable, Iterable, Iterator
+from concurrent.futures import Executor
+from contextvars import ContextVar
+from typing import TYPE_CHECKING, Any
+
+from fsspec.asyn import sync_wrapper
+from opendal.exceptions import (
+    ConditionNotMatch,
+    NotFound,
+    PermissionDenied,
+    Unexpected,
+    Unsupported,
+)
+
+from .exceptions import translate_error
+
+logger = logging.getLogger("opendalfs")
+
+if TYPE_CHECKING:
+    from .fs import OpendalFileSystem
+
+# Operator methods taking one path, or a source and a target path, first.
+_PATH_METHODS = frozenset(
+    {
+        "read",
+        "write",
+        "stat",
+        "exists",
+        "delete",
+        "create_dir",
+        "remove_all",
+        "list",
+        "scan",
+        "open",
+        "presign_read",
+        "presign_write",
+        "presign_stat",
+        "presign_delete",
+    }
+)
+_TWO_PATH_METHODS = frozenset({"copy", "rename"})
+
+# Limit ``OpendalFileSystem._call`` sets for the backend calls it makes, each
+# page of a listing and each step of an open reader or writer: ``None`` when it
+# bounds a single call as a whole itself. Unset outside ``_call``, where the
+# filesystem's ``timeout`` and ``io_timeout`` apply.
+_call_limit: ContextVar[tuple[float | None] | None] = ContextVar(
+    "opendalfs_call_limit", default=None
+)
+
+
+@contextlib.contextmanager
+def call_limit(limit: float | None) -> Iterator[None]:
+    """Bound each backend call made in this context by ``limit`` seconds"""
+    token = _call_limit.set((limit,))
+    try:
+        yield
+    finally:
+        _call_limit.reset(token)
+
+
+def _limit(default: float | None) -> float | None:
+    override = _call_limit.get()
+    return default if override is None else override[0]
+
+
+def _bounded(pending: Any, limit: float | None) -> Any:
+    return pending if limit is None else asyncio.wait_for(pending, limit)
+
+# Calls returning a lister, whose pages are bounded like single calls.
+_LIST_METHODS = frozenset({"list", "scan"})
+
+# Steps of an open reader or writer bounded by the filesystem's ``io_timeout``.
+_IO_METHODS = frozenset({"read", "write", "seek", "close"})
+
+# Stats names for the transfer steps of open readers and writers.
+_STREAM_OPERATIONS = {"read": "stream_read", "write": "stream_write"}
+
+# Errors the filesystem catches itself and translates where needed.
+_HANDLED_ERRORS = (NotFound, PermissionDenied, Unsupported, Unexpected, ConditionNotMatch)
+
+
+def _nbytes(value: Any) -> int:
+    try:
+        return memoryview(value).nbytes
+    except TypeError:
+        return 0
+
+
+def _translated(err: Exception, path: Any) -> BaseException:
+    if isinstance(err, _HANDLED_ERRORS):
+        return err
+    return translate_error(err, path if isinstance(path, str) else None)
+
+
+# Set while a filesystem method runs, so only the outermost one translates.
+_in_method: ContextVar[bool] = ContextVar("opendalfs_in_method", default=False)
+
+
+def _translating(method: Callable[..., Any]) -> Callable[..., Any]:
+    @functools.wraps(method)
+    async def wrapper(self: Any, *args: Any, **kwargs: Any) -> Any:
+        if _in_method.get():
+            return await method(self, *args, **kwargs)
+        token = _in_method.set(True)
+        try:
+            return await method(self, *args, **kwargs)
+        except _HANDLED_ERRORS as err:
+            path = args[0] if args and isinstance(args[0], str) else None
+            translated = translate_error(err, path)
+            if translated is err:
+                raise
+            raise translated from err
+        finally:
+            _in_method.reset(token)
+
+    return wrapper
+
+
+def translate_handled_errors(cls: type) -> None:
+    """Translate the errors the filesystem handles itself where they leave it
+
+    Wraps the async methods of ``cls`` that have a public counterpart, and the
+    sync wrappers defined for them, so OpenDAL errors escaping a call go
+    through ``ERROR_TRANSLATIONS`` like those of every other operator call.
+    Only the outermost method translates; those it calls see the originals.
+    """
+    for name, method in list(vars(cls).items()):
+        if (
+            not name.startswith("_")
+            or name.startswith("__")
+            or not inspect.iscoroutinefunction(method)
+            or not hasattr(cls, name[1:])
+        ):
+            continue
+        wrapped = _translating(method)
+        setattr(cls, name, wrapped)
+        public = vars(cls).get(name[1:])
+        if getattr(public, "__wrapped__", None) is method:
+            setattr(cls, name[1:], sync_wrapper(wrapped))
+
+
+class HookedOperator:
+    """Operator wrapper routing path-based calls through filesystem hooks.
+
+    Every call taking paths goes through ``_before_operation``, has its paths
+    mapped by ``_to_backend_path`` and its result passed to
+    ``_after_operation`` on the owning filesystem; errors are translated
+    through ``ERROR_TRANSLATIONS``. Anything else is forwarded to the wrapped
+    operator untouched. ``inflight`` counts path-based calls still running.
+
+    Async calls and each page of the listings they return are bounded by the
+    filesystem's ``timeout``, and the readers and writers they open by its
+    ``io_timeout`` for each step; within ``call_limit`` its limit applies to
+    all of them instead. Every call is counted in the filesystem's
+    ``stats()`` and, with its ``logging`` on, logged with its duration; with
+    ``debug_signing`` on, rejected calls log how they were signed.
+    """
+
+    def __init__(self, fs: OpendalFileSystem, operator: Any, asynchronous: bool) -> None:
+        self._fs = fs
+        self._operator = operator
+        self._asynchronous = asynchronous
+        self._inflight = 0
+        self._lock = threading.Lock()
+
+    @property
+    def inflight(self) -> int:
+        return self._inflight
+
+    def _track(self, delta: int) -> None:
+        with self._lock:
+            self._inflight += delta
+
+    @property
+    def inner(self) -> Any:
+        """The wrapped OpenDAL operator"""
+        return self._operator
+
+    def __getattr__(self, name: str) -> Any:
+        attr = getattr(self._operator, name)
+        if name in _TWO_PATH_METHODS:
+            npaths = 2
+        elif name in _PATH_METHODS:
+            npaths = 1
+        else:
+            return attr
+
+        fs = self._fs
+
+        def prepare(args: tuple[Any, ...]) -> tuple[Any, ...]:
+            fs._before_operation(name, args[0])
+            paths = [fs._to_backend_path(path) for path in args[:npaths]]
+            return (*paths, *args[npaths:])
+
+        def finish(
+            args: tuple[Any, ...], start: float, result: Any, err: BaseException | None
+        ) -> None:
+            elapsed = time.perf_counter() - start
+            nbytes = 0
+            if err is None and name == "read":
+                nbytes = _nbytes(result)
+            elif err is None and name == "write" and len(args) > 1:
+                nbytes = _nbytes(args[1])
+            fs._stats.record(name, elapsed, nbytes, failed=err is not None)
+            if fs.debug_signing and isinstance(err, PermissionDenied):
+                fs._log_signing(name, args[0])
+            if not fs.logging:
+                return
+            target = " -> ".join(str(path) for path in args[:npaths])
+            if err is None:
+                logger.log(fs.log_level, "%s %s took %.3fs", name, target, elapsed)
+            else:
+                logger.log(
+                    fs.log_level, "%s %s failed after %.3fs: %r", name, target, elapsed, err
+                )
+
+        if self._asynchronous:
+
+            async def call_async(*args: Any, **kwargs: Any) -> Any:
+                self._track(1)
+                start = time.perf_counter()
+                try:
+                    result = await _bounded(attr(*prepare(args), **kwargs), _limit(fs.timeout))
+                except Exception as err:
+                    finish(args, start, None, err)
+                    translated = _translated(err, args[0])
+                    if translated is err:
+                        raise
+                    raise translated from err
+                finally:
+                    self._track(-1)
+                finish(args, start, result, None)
+                if name == "open":
+                    result = _HookedFile(fs, result, True)
+                elif name in _LIST_METHODS:
+                    result = _HookedLister(fs, result)
+                return fs._after_operation(name, args[0], result)
+
+            return call_async
+
+        def call(*args: Any, **kwargs: Any) -> Any:
+            self._track(1)
+            start = time.perf_counter()
+            try:
+                result = attr(*prepare(args), **kwargs)
+            except Exception as err:
+                finish(args, start, None, err)
+                translated = _translated(err, args[0])
+                if translated is err:
+                    raise
+                raise translated from err
+            finally:
+                self._track(-1)
+            finish(args, start, result, None)
+            if name == "open":
+                result = _HookedFile(fs, result, False)
+            return fs._after_operation(name, args[0], result)
+
+        return call
+
+
+class _HookedFile:
+    """OpenDAL file counting its transfers in the filesystem's stats.
+
+    Steps of async files fail after the filesystem's ``io_timeout``.
+    """
+
+    def __init__(self, fs: OpendalFileSystem, file: Any, asynchronous: bool) -> None:
+        self._fs = fs
+        self._file = file
+        self._asynchronous = asynchronous
+
+    def __getattr__(self, name: str) -> Any:
+        attr = getattr(self._file, name)
+        if name not in _IO_METHODS:
+            return attr
+
+        fs = self._fs
+        operation = _STREAM_OPERATIONS.get(name)
+
+        def finish(args: tuple[Any, ...], start: float, result: Any, failed: bool) -> None:
+            if operation is None:
+                return
+            nbytes = 0
+            if not failed and name == "read":
+                nbytes = _nbytes(result)
+            elif not failed and args:
+                nbytes = _nbytes(args[0])
+            fs._stats.record(operation, time.perf_counter() - start, nbytes, failed)
+
+        if self._asynchronous:
+
+            async def call_async(*args: Any, **kwargs: Any) -> Any:
+                start = time.perf_counter()
+                try:
+                    result = await _bounded(attr(*args, **kwargs), _limit(fs.io_timeout))
+                except Exception:
+                    finish(args, start, None, True)
+                    raise
+                finish(args, start, result, False)
+                return result
+
+            return call_async
+
+        def call(*args: Any, **kwargs: Any) -> Any:
+            start = time.perf_counter()
+            try:
+                result = attr(*args, **kwargs)
+            except Exception:
+                finish(args, start, None, True)
+                raise
+            finish(args, start, result, False)
+            return result
+
+        return call
+
+
+class _HookedLister:
+    """Async OpenDAL lister bounding each entry, and so each page fetched for
+    one, by the filesystem's ``timeout``.
+    """
+
+    def __init__(self, fs: OpendalFileSystem, lister: Any) -> None:
+        self._fs = fs
+        self._lister = lister
+
+    def __aiter__(self) -> _HookedLister:
+        return self
+
+    async def __anext__(self) -> Any:
+        return await _bounded(self._lister.__anext__(), _limit(self._fs.timeout))
+
+
+# Methods of the async operator that are called without awaiting.
+_NON_AWAITED_METHODS = frozenset({"capability"})
+
+
+async def _in_thread(
+    executor: Executor | None, func: Callable[..., Any], *args: Any, **kwargs: Any
+) -> Any:
+    """Run a blocking call on ``executor``, or asyncio's default one"""
+    if executor is None:
+        return await asyncio.to_thread(func, *args, **kwargs)
+    loop = asyncio.get_running_loop()
+    return await loop.run_in_executor(executor, functools.partial(func, *args, **kwargs))
+
+
+async def _iterate_in_thread(
+    iterable: Iterable[Any], executor: Executor | None = None
+) -> AsyncIterator[Any]:
+    iterator = iter(iterable)
+    done = object()
+    while True:
+        item = await _in_thread(executor, next, iterator, done)
+        if item is done:
+            return
+        yield item
+
+
+class _ThreadedFile:
+    """Async facade over a blocking OpenDAL file"""
+
+    def __init__(self, file: Any, executor: Executor | None = None) -> None:
+        self._file = file
+        self._executor = executor
+
+    def __getattr__(self, name: str) -> Any:
+        attr = getattr(self._file, name)
+        if not callable(attr):
+            return attr
+
+        async def call(*args: Any, **kwargs: Any) -> Any:
+            return await _in_thread(self._executor, attr, *args, **kwargs)
+
+        return call
+
+
+class BlockingAsyncOperator:
+    """Async operator interface served by a blocking operator.
+
+    Calls run on worker threads, so the filesystem's async API keeps working
+    without OpenDAL's async runtime, e.g. on musl wheels or in sandboxes
+    that forbid spawning it. Calls go to ``executor`` when given, else to
+    asyncio's default executor.
+    """
+
+    def __init__(self, operator: Any, executor: Executor | None = None) -> None:
+        self._operator = operator
+        self._executor = executor
+
+    def __getattr__(self, name: str) -> Any:
+        attr = getattr(self._operator, name)
+        if name in _NON_AWAITED_METHODS or not callable(attr):
+            return attr
+
+        async def call(*args: Any, **kwargs: Any) -> Any:
+            result = await _in_thread(self._executor, attr, *args, **kwargs)
+            if name in ("list", "scan"):
+                return _iterate_in_thread(result, self._executor)
+            if name == "open":
+                return _ThreadedFile(result, self._executor)
+            return result
+
+        return call
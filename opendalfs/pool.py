@@ -0,0 +1,64 @@
+from __future__ import annotations
+
+import json
+import threading
+from collections import OrderedDict
+from typing import Any
+
+from .fs import OpendalFileSystem
+
+
+class OperatorPool:
+    """Bounded cache of filesystems for applications using many buckets.
+
+    Filesystems are keyed by scheme, bucket, region and any other options,
+    and the least recently used one is dropped once ``max_size`` is reached,
+    releasing its operator and connections. Instances bypass fsspec's own
+    instance cache, which never evicts.
+    """
+
+    def __init__(self, max_size: int = 64, **defaults: Any) -> None:
+        if max_size < 1:
+            raise ValueError("max_size must be at least 1")
+        self.max_size = max_size
+        self.defaults = defaults
+        self._filesystems: OrderedDict[tuple[str, str], OpendalFileSystem] = OrderedDict()
+        self._lock = threading.Lock()
+
+    def get(
+        self,
+        scheme: str,
+        bucket: str | None = None,
+        region: str | None = None,
+        **options: Any,
+    ) -> OpendalFileSystem:
+        """Return the pooled filesystem for these options, creating it if needed"""
+        options = {**self.defaults, **options}
+        if bucket is not None:
+            options["bucket"] = bucket
+        if region is not None:
+            options["region"] = region
+        # Options may hold dicts or lists, e.g. ``retry`` or ``replicas``.
+        key = (scheme, json.dumps(options, sort_keys=True, default=repr))
+
+        with self._lock:
+            fs = self._filesystems.get(key)
+            if fs is not None:
+                self._filesystems.move_to_end(key)
+                return fs
+
+        fs = OpendalFileSystem(scheme, skip_instance_cache=True, **options)
+        with self._lock:
+            # Another thread may have created the same entry meanwhile.
+            fs = self._filesystems.setdefault(key, fs)
+            self._filesystems.move_to_end(key)
+            while len(self._filesystems) > self.max_size:
+                self._filesystems.popitem(last=False)
+        return fs
+
+    def clear(self) -> None:
+        with self._lock:
+            self._filesystems.clear()
+
+    def __len__(self) -> int:
+        return len(self._filesystems)
@@ -0,0 +1,20 @@
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+
+# HTTP method to the OpenDAL operator call presigning it.
+PRESIGN_METHODS = {
+    "GET": "presign_read",
+    "HEAD": "presign_stat",
+    "PUT": "presign_write",
+    "DELETE": "presign_delete",
+}
+
+
+@dataclass(frozen=True)
+class SignedRequest:
+    """A presigned request: send ``headers`` along with it to ``url``"""
+
+    method: str
+    url: str
+    headers: dict[str, str] = field(default_factory=dict)
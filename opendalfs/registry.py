@@ -1,7 +1,11 @@
 from __future__ import annotations
 
+import re
+import threading
 from typing import Any, ClassVar
-from urllib.parse import parse_qsl, urlsplit
+from urllib.parse import parse_qsl
+
+from fsspec.asyn import sync_wrapper
 
 from .fs import OpendalFileSystem
 
@@ -12,26 +16,66 @@ _DEFAULT_CONTAINER_KEY_BY_SERVICE: dict[str, str] = {
 
 _DYNAMIC_FILESYSTEMS: dict[str, type[_OpendalServiceFileSystem]] = {}
 
+# Registration checks and then updates both our table and fsspec's, which
+# must not interleave between threads, notably on free-threaded builds.
+_REGISTRY_LOCK = threading.RLock()
+
+# Well-known development account built into the Azurite emulator.
+AZURITE_ACCOUNT_NAME = "devstoreaccount1"
+AZURITE_ACCOUNT_KEY = (
+    "Eby8vdM02xNOcqFlqUwJPLlmEtlCDXJ1OUzFT50uSRZ6IFsuFq2UVErCz4I6tq/K1SZFPTOtr/KBHBeksoGMGw=="
+)
+AZURITE_ENDPOINT = "http://127.0.0.1:10000/devstoreaccount1"
+
+_AZBLOB_CREDENTIAL_KEYS = ("account_key", "sas_token")
+
+FAKE_GCS_SERVER_ENDPOINT = "http://localhost:4443"
+
+# Default port of the Alluxio proxy's REST API.
+ALLUXIO_PROXY_PORT = 39999
+
+
+# A trailing query is only taken as options when every part is ``name=value``,
+# so keys that merely contain ``?`` are left intact.
+_QUERY_OPTIONS = re.compile(r"[A-Za-z_][A-Za-z0-9_]*=[^&]*(&[A-Za-z_][A-Za-z0-9_]*=[^&]*)*")
+
 
 def _parse_opendal_url(url: str) -> tuple[str | None, str | None, str, dict[str, str]]:
+    """Split a URL into scheme, container, key and query options
+
+    Keys are taken verbatim: ``#`` is not a fragment, ``%`` sequences are not
+    decoded and the container keeps its case, since object stores allow all
+    of these in names.
+    """
     if "://" not in url:
         return None, None, url.lstrip("/"), {}
 
-    parsed = urlsplit(url)
-    scheme = parsed.scheme or None
-    host = parsed.hostname or parsed.netloc or None
-    path = (parsed.path or "").lstrip("/")
-    query = {k: v for k, v in parse_qsl(parsed.query, keep_blank_values=True)}
-    return scheme, host, path, query
+    scheme, _, rest = url.partition("://")
+    host, _, path = rest.partition("/")
+    query: dict[str, str] = {}
+    if "?" in path or not path:
+        head, sep, tail = (path or host).rpartition("?")
+        if sep and _QUERY_OPTIONS.fullmatch(tail):
+            query = dict(parse_qsl(tail, keep_blank_values=True))
+            if path:
+                path = head
+            else:
+                host = head
+    return scheme or None, host or None, path.lstrip("/"), query
 
 
 class _OpendalServiceFileSystem(OpendalFileSystem):
     protocol: ClassVar[str]
     service: ClassVar[str]
     container_key: ClassVar[str] = "bucket"
+    default_options: ClassVar[dict[str, str]] = {}
+    # Further URL schemes the class accepts, registered only on request.
+    aliases: ClassVar[tuple[str, ...]] = ()
 
     def __init__(self, *args: Any, **kwargs: Any) -> None:
         kwargs.pop("scheme", None)
+        for key, value in type(self).default_options.items():
+            kwargs.setdefault(key, value)
         super().__init__(type(self).service, *args, **kwargs)
 
     @classmethod
@@ -44,14 +88,14 @@ class _OpendalServiceFileSystem(OpendalFileSystem):
         scheme, _host, stripped, _query = _parse_opendal_url(path)
         if scheme is None:
             return stripped
-        if scheme != cls.protocol:
+        if scheme != cls.protocol and scheme not in cls.aliases:
             return path
         return stripped
 
     @classmethod
     def _get_kwargs_from_urls(cls, path: str) -> dict[str, Any]:
         scheme, host, _stripped, query = _parse_opendal_url(path)
-        if scheme is not None and scheme != cls.protocol:
+        if scheme is not None and scheme != cls.protocol and scheme not in cls.aliases:
             return {}
 
         kwargs: dict[str, Any] = dict(query)
@@ -71,57 +115,300 @@ class OpendalGCSFileSystem(_OpendalServiceFileSystem):
     service = "gcs"
     container_key = "bucket"
 
+    @classmethod
+    def fake_gcs_server(
+        cls,
+        bucket: str,
+        endpoint: str = FAKE_GCS_SERVER_ENDPOINT,
+        **kwargs: Any,
+    ) -> OpendalGCSFileSystem:
+        """Connect to a fake-gcs-server emulator without GCP credentials
+
+        The emulator accepts unsigned requests over plain HTTP, so credential
+        discovery (config files and the VM metadata server) is turned off.
+        """
+        options: dict[str, Any] = {
+            "bucket": bucket,
+            "endpoint": endpoint,
+            "allow_anonymous": "true",
+            "disable_vm_metadata": "true",
+            "disable_config_load": "true",
+        }
+        options.update(kwargs)
+        return cls(**options)
+
 
 class OpendalAzBlobFileSystem(_OpendalServiceFileSystem):
+    """Azure Blob Storage.
+
+    Also accepts ``az://`` and ``abfs://`` URLs, where the host may be given
+    as ``container@account.dfs.core.windows.net``; those schemes are only
+    registered with fsspec through ``register_opendal_protocols(aliases=True)``
+    so that adlfs keeps them otherwise.
+
+    Pass ``anonymous=True`` to read public containers: credentials given in
+    the options are dropped so requests go out unsigned.
+    """
+
     protocol = "opendal+azblob"
     service = "azblob"
     container_key = "container"
+    aliases = ("az", "abfs")
+
+    def __init__(
+        self,
+        *args: Any,
+        container: str | None = None,
+        endpoint: str | None = None,
+        account_name: str | None = None,
+        account_key: str | None = None,
+        sas_token: str | None = None,
+        root: str | None = None,
+        anonymous: bool = False,
+        **kwargs: Any,
+    ) -> None:
+        options = {
+            "container": container,
+            "endpoint": endpoint,
+            "account_name": account_name,
+            "account_key": account_key,
+            "sas_token": sas_token,
+            "root": root,
+        }
+        for key, value in options.items():
+            if value is not None:
+                kwargs[key] = value
+        if kwargs.get("account_name") and not kwargs.get("endpoint"):
+            kwargs["endpoint"] = f"https://{kwargs['account_name']}.blob.core.windows.net"
+        if anonymous:
+            for key in _AZBLOB_CREDENTIAL_KEYS:
+                kwargs.pop(key, None)
+        super().__init__(*args, **kwargs)
+
+    @classmethod
+    def _get_kwargs_from_urls(cls, path: str) -> dict[str, Any]:
+        kwargs = super()._get_kwargs_from_urls(path)
+        container = kwargs.get("container")
+        if container and "@" in container:
+            container, _, host = container.partition("@")
+            kwargs["container"] = container
+            kwargs.setdefault("account_name", host.split(".", 1)[0])
+        return kwargs
+
+    @classmethod
+    def azurite(
+        cls,
+        container: str,
+        endpoint: str = AZURITE_ENDPOINT,
+        **kwargs: Any,
+    ) -> OpendalAzBlobFileSystem:
+        """Connect to a local Azurite emulator with its default account"""
+        options: dict[str, Any] = {
+            "container": container,
+            "endpoint": endpoint,
+            "account_name": AZURITE_ACCOUNT_NAME,
+            "account_key": AZURITE_ACCOUNT_KEY,
+        }
+        options.update(kwargs)
+        return cls(**options)
+
+
+class OpendalOSSFileSystem(_OpendalServiceFileSystem):
+    """Alibaba Cloud Object Storage Service.
+
+    Without an ``endpoint``, the public endpoint of ``region`` (for example
+    ``oss-cn-hangzhou``) is used.
+    """
+
+    protocol = "opendal+oss"
+    service = "oss"
+    container_key = "bucket"
+
+    def __init__(
+        self,
+        *args: Any,
+        bucket: str | None = None,
+        endpoint: str | None = None,
+        access_key_id: str | None = None,
+        access_key_secret: str | None = None,
+        root: str | None = None,
+        region: str | None = None,
+        **kwargs: Any,
+    ) -> None:
+        options = {
+            "bucket": bucket,
+            "endpoint": endpoint,
+            "access_key_id": access_key_id,
+            "access_key_secret": access_key_secret,
+            "root": root,
+        }
+        for key, value in options.items():
+            if value is not None:
+                kwargs[key] = value
+        if region and not kwargs.get("endpoint"):
+            kwargs["endpoint"] = f"https://{region}.aliyuncs.com"
+        super().__init__(*args, **kwargs)
+
+
+class OpendalCephRGWFileSystem(_OpendalServiceFileSystem):
+    """Ceph RADOS Gateway through its S3 API.
+
+    RGW serves buckets path-style from a single endpoint, so virtual-host
+    addressing and AWS config discovery are disabled by default. Passing
+    ``keystone_token`` switches to RGW's Swift API with Keystone auth, using
+    the bucket as the Swift container; ``scheme`` is kept as ``"swift"`` in
+    ``storage_options`` so filesystems rebuilt from them stay on Swift.
+    """
+
+    protocol = "opendal+rgw"
+    service = "s3"
+    container_key = "bucket"
+    default_options = {
+        "region": "us-east-1",
+        "disable_config_load": "true",
+        "enable_virtual_host_style": "false",
+    }
+
+    def __init__(self, *args: Any, **kwargs: Any) -> None:
+        if not kwargs.get("endpoint"):
+            raise ValueError("Ceph RGW needs an explicit endpoint")
+
+        token = kwargs.pop("keystone_token", None)
+        if token is None and kwargs.get("scheme") != "swift":
+            super().__init__(*args, **kwargs)
+            return
+
+        kwargs.pop("scheme", None)
+        if "bucket" in kwargs:
+            kwargs.setdefault("container", kwargs.pop("bucket"))
+        if token is not None:
+            kwargs["token"] = token
+        OpendalFileSystem.__init__(self, "swift", *args, **kwargs)
+        self.storage_options["scheme"] = "swift"
+
+    async def _reconfigure(self, drain_timeout: float | None = 30.0, **options: Any) -> None:
+        if self.scheme == "swift" and "keystone_token" in options:
+            options["token"] = options.pop("keystone_token")
+        await super()._reconfigure(drain_timeout, **options)
+
+    reconfigure = sync_wrapper(_reconfigure)
+
+
+class OpendalLocalFileSystem(_OpendalServiceFileSystem):
+    """Local disk through OpenDAL's ``fs`` service.
+
+    Paths are absolute and there is no container, so pipelines can be tried
+    against ``opendal+fs:///tmp/data`` and later pointed at a bucket with only
+    the URL changed. Pass ``root`` to confine keys to a directory.
+    """
+
+    protocol = "opendal+fs"
+    service = "fs"
+    container_key = "root"
+    default_options = {"root": "/"}
+
+    @classmethod
+    def _strip_protocol(cls, path: Any) -> Any:
+        if isinstance(path, str) and path.startswith(f"{cls.protocol}://"):
+            # The URL host is the first path component, not a container.
+            return path[len(cls.protocol) + 3 :].lstrip("/")
+        return super()._strip_protocol(path)
+
+    @classmethod
+    def _get_kwargs_from_urls(cls, path: str) -> dict[str, Any]:
+        return {}
+
+
+class OpendalAlluxioFileSystem(_OpendalServiceFileSystem):
+    """Alluxio caching tier through its proxy's REST API.
+
+    The URL host names the proxy, so ``opendal+alluxio://proxy/data/file``
+    reads ``/data/file`` through ``http://proxy:39999``; pass ``endpoint``
+    for HTTPS or a gateway in front of the proxy, and ``root`` to confine
+    keys to a directory.
+    """
+
+    protocol = "opendal+alluxio"
+    service = "alluxio"
+    container_key = "endpoint"
+
+    def __init__(self, *args: Any, **kwargs: Any) -> None:
+        if not kwargs.get("endpoint"):
+            raise ValueError("Alluxio needs the proxy endpoint or a URL naming it")
+        super().__init__(*args, **kwargs)
+
+    @classmethod
+    def _get_kwargs_from_urls(cls, path: str) -> dict[str, Any]:
+        kwargs = super()._get_kwargs_from_urls(path)
+        host = kwargs.get("endpoint")
+        if host and "://" not in host:
+            if ":" not in host:
+                host = f"{host}:{ALLUXIO_PROXY_PORT}"
+            kwargs["endpoint"] = f"http://{host}"
+        return kwargs
 
 
 def register_opendal_service(service: str, *, container_key: str | None = None) -> str:
     from fsspec.registry import register_implementation
 
     protocol = f"opendal+{service}"
-    if protocol not in _DYNAMIC_FILESYSTEMS:
-        key = container_key or _DEFAULT_CONTAINER_KEY_BY_SERVICE.get(service, "bucket")
-        safe = "".join([c if c.isalnum() else "_" for c in service])
-        name = f"Opendal_{safe}_FileSystem"
-        cls = type(
-            name,
-            (_OpendalServiceFileSystem,),
-            {
-                "protocol": protocol,
-                "service": service,
-                "container_key": key,
-            },
-        )
-        _DYNAMIC_FILESYSTEMS[protocol] = cls
-
-    register_implementation(protocol, _DYNAMIC_FILESYSTEMS[protocol])
+    with _REGISTRY_LOCK:
+        if protocol not in _DYNAMIC_FILESYSTEMS:
+            key = container_key or _DEFAULT_CONTAINER_KEY_BY_SERVICE.get(service, "bucket")
+            safe = "".join([c if c.isalnum() else "_" for c in service])
+            name = f"Opendal_{safe}_FileSystem"
+            cls = type(
+                name,
+                (_OpendalServiceFileSystem,),
+                {
+                    "protocol": protocol,
+                    "service": service,
+                    "container_key": key,
+                },
+            )
+            _DYNAMIC_FILESYSTEMS[protocol] = cls
+
+        register_implementation(protocol, _DYNAMIC_FILESYSTEMS[protocol])
     return protocol
 
 
-def register_opendal_protocols(services: list[str] | None = None) -> list[str]:
+def register_opendal_protocols(
+    services: list[str] | None = None, *, aliases: bool = False
+) -> list[str]:
+    """Register ``opendal+<service>`` protocols with fsspec
+
+    With ``aliases=True`` the builtin classes also claim their plain schemes,
+    e.g. ``az://`` and ``abfs://`` for Azure Blob, replacing any other
+    implementation registered for them.
+    """
     from fsspec.registry import register_implementation
 
-    builtins: dict[str, type[OpendalFileSystem]] = {
+    builtins: dict[str, type[_OpendalServiceFileSystem]] = {
         "opendal+s3": OpendalS3FileSystem,
         "opendal+gcs": OpendalGCSFileSystem,
         "opendal+azblob": OpendalAzBlobFileSystem,
+        "opendal+rgw": OpendalCephRGWFileSystem,
+        "opendal+oss": OpendalOSSFileSystem,
+        "opendal+fs": OpendalLocalFileSystem,
+        "opendal+alluxio": OpendalAlluxioFileSystem,
     }
 
     if services is None:
-        for protocol, cls in builtins.items():
-            register_implementation(protocol, cls)
-        return sorted(builtins.keys())
+        services = [protocol.removeprefix("opendal+") for protocol in builtins]
 
     registered: list[str] = []
-    for service in services:
-        protocol = f"opendal+{service}"
-        if protocol in builtins:
-            register_implementation(protocol, builtins[protocol])
+    with _REGISTRY_LOCK:
+        for service in services:
+            protocol = f"opendal+{service}"
+            if protocol not in builtins:
+                registered.append(register_opendal_service(service))
+                continue
+            cls = builtins[protocol]
+            register_implementation(protocol, cls)
             registered.append(protocol)
-        else:
-            registered.append(register_opendal_service(service))
+            if aliases:
+                for alias in cls.aliases:
+                    register_implementation(alias, cls, clobber=True)
+                    registered.append(alias)
 
     return sorted(set(registered))
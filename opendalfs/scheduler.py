@@ -0,0 +1,72 @@
+from __future__ import annotations
+
+import asyncio
+import contextlib
+import heapq
+import itertools
+from collections.abc import AsyncIterator
+
+PRIORITIES = {"high": 0, "normal": 1, "low": 2}
+
+
+def priority_rank(priority: str) -> int:
+    try:
+        return PRIORITIES[priority]
+    except KeyError:
+        raise ValueError(
+            f"priority must be one of {sorted(PRIORITIES)}, got {priority!r}"
+        ) from None
+
+
+class PriorityScheduler:
+    """Concurrency limit admitting waiting calls by priority.
+
+    At most ``slots`` calls hold a slot at once. When one is freed it goes to
+    the oldest waiting ``"high"`` call, then ``"normal"``, then ``"low"``, so
+    interactive reads overtake queued bulk traffic. Low priority calls wait
+    as long as higher ones keep arriving.
+    """
+
+    def __init__(self, slots: int) -> None:
+        if slots < 1:
+            raise ValueError("slots must be at least 1")
+        self.slots = slots
+        self.active = 0
+        self._waiters: list[tuple[int, int, asyncio.Future[None]]] = []
+        self._order = itertools.count()
+
+    @property
+    def waiting(self) -> int:
+        """Calls queued for a slot"""
+        return sum(not future.done() for _, _, future in self._waiters)
+
+    @contextlib.asynccontextmanager
+    async def slot(self, priority: str = "normal") -> AsyncIterator[None]:
+        rank = priority_rank(priority)
+        if self.active < self.slots and not self._waiters:
+            self.active += 1
+        else:
+            future = asyncio.get_running_loop().create_future()
+            heapq.heappush(self._waiters, (rank, next(self._order), future))
+            try:
+                await future
+            except asyncio.CancelledError:
+                # A slot handed over just before cancellation is passed on.
+                if future.done() and not future.cancelled():
+                    self._release()
+                else:
+                    future.cancel()
+                raise
+        try:
+            yield
+        finally:
+            self._release()
+
+    def _release(self) -> None:
+        while self._waiters:
+            _, _, future = heapq.heappop(self._waiters)
+            if not future.done():
+                # The slot moves to the waiter, so ``active`` is unchanged.
+                future.set_result(None)
+                return
+        self.active -= 1
@@ -0,0 +1,73 @@
+from __future__ import annotations
+
+import asyncio
+import random
+from typing import TYPE_CHECKING
+
+from fsspec.asyn import sync
+from opendal.exceptions import ConditionNotMatch, NotFound
+
+from .exceptions import CapabilityError, FileChangedError
+
+if TYPE_CHECKING:
+    from .fs import OpendalFileSystem
+
+
+class Sequence:
+    """Counter stored in one object, incremented with conditional writes.
+
+    ``next()`` returns the stored value and stores the next one, writing
+    only if the object still has the ETag it was read with, so concurrent
+    callers across processes never get the same value. A missing object
+    starts at ``start``. Losing a race retries after a short random backoff,
+    up to ``max_attempts`` times. Needs a backend with conditional writes,
+    such as S3, GCS or Azure Blob.
+    """
+
+    def __init__(
+        self, fs: OpendalFileSystem, path: str, start: int = 0, max_attempts: int = 16
+    ) -> None:
+        if max_attempts < 1:
+            raise ValueError("max_attempts must be at least 1")
+        self.fs = fs
+        self.path = fs._strip_protocol(path)
+        self.start = start
+        self.max_attempts = max_attempts
+
+    async def _next(self) -> int:
+        fs = self.fs
+        cap = fs.async_fs.capability()
+        for capability in ("write_with_if_match", "write_with_if_not_exists"):
+            if not getattr(cap, capability, False):
+                raise CapabilityError(fs.scheme, capability)
+
+        for attempt in range(self.max_attempts):
+            try:
+                try:
+                    etag = (await fs.async_fs.stat(self.path)).etag
+                except NotFound:
+                    value, options = self.start, {"if_not_exists": True}
+                else:
+                    data = await fs._read(self.path, if_match=etag)
+                    value, options = int(bytes(data)), {"if_match": etag}
+                await fs.async_fs.write(self.path, str(value + 1).encode(), **options)
+            except (ConditionNotMatch, FileChangedError):
+                await asyncio.sleep(random.uniform(0, min(0.05 * 2**attempt, 2.0)))
+                continue
+            fs.invalidate_cache(fs._parent(self.path))
+            return value
+        raise RuntimeError(f"{self.path} still contended after {self.max_attempts} attempts")
+
+    def next(self) -> int:
+        """Reserve and return the next value"""
+        return sync(self.fs.loop, self._next)
+
+    async def _current(self) -> int:
+        try:
+            return int(bytes(await self.fs._cat_file(self.path)))
+        except (FileNotFoundError, NotFound):
+            return self.start
+
+    def current(self) -> int:
+        """The value ``next()`` would return now, without reserving it"""
+        return sync(self.fs.loop, self._current)
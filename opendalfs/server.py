@@ -0,0 +1,158 @@
+"""Read-only HTTP server for a filesystem, for tools that only take URLs.
+
+Not imported by ``opendalfs`` itself; import this module to opt in. Objects
+are served with the filesystem's credentials, so bind it to loopback unless
+every client may read everything the filesystem can.
+"""
+
+from __future__ import annotations
+
+import re
+import threading
+from email.utils import formatdate
+from http import HTTPStatus
+from http.server import BaseHTTPRequestHandler, ThreadingHTTPServer
+from typing import TYPE_CHECKING, Any
+from urllib.parse import unquote, urlsplit
+
+if TYPE_CHECKING:
+    from .fs import OpendalFileSystem
+
+_RANGE = re.compile(r"bytes=(\d*)-(\d*)")
+_CHUNK_SIZE = 1 << 20
+
+
+def parse_range(header: str | None, size: int) -> tuple[int, int] | None:
+    """Resolve a single-range ``Range`` header to an inclusive byte range
+
+    Returns ``None`` when the whole object should be sent, and raises
+    ``ValueError`` when the range cannot be satisfied.
+    """
+    if not header:
+        return None
+    match = _RANGE.fullmatch(header.strip())
+    if match is None:
+        # Multiple ranges and other units are allowed to get the full body.
+        return None
+    first, last = match.groups()
+    if not first:
+        if not last:
+            return None
+        length = min(int(last), size)
+        if length == 0:
+            raise ValueError(header)
+        return size - length, size - 1
+    start = int(first)
+    end = min(int(last), size - 1) if last else size - 1
+    if start >= size or start > end:
+        raise ValueError(header)
+    return start, end
+
+
+class RangeRequestHandler(BaseHTTPRequestHandler):
+    server: RangeServer
+
+    def do_HEAD(self) -> None:
+        self._serve(body=False)
+
+    def do_GET(self) -> None:
+        self._serve(body=True)
+
+    def _serve(self, body: bool) -> None:
+        fs = self.server.fs
+        path = unquote(urlsplit(self.path).path).lstrip("/")
+        try:
+            info = fs.info(path) if path else None
+        except FileNotFoundError:
+            info = None
+        if info is None or info["type"] != "file":
+            self.send_error(HTTPStatus.NOT_FOUND)
+            return
+
+        size = info["size"]
+        try:
+            byte_range = parse_range(self.headers.get("Range"), size)
+        except ValueError:
+            self.send_response(HTTPStatus.REQUESTED_RANGE_NOT_SATISFIABLE)
+            self.send_header("Content-Range", f"bytes */{size}")
+            self.send_header("Content-Length", "0")
+            self.end_headers()
+            return
+
+        if byte_range is None:
+            self.send_response(HTTPStatus.OK)
+            start, length = 0, size
+        else:
+            self.send_response(HTTPStatus.PARTIAL_CONTENT)
+            start, length = byte_range[0], byte_range[1] - byte_range[0] + 1
+            self.send_header("Content-Range", f"bytes {byte_range[0]}-{byte_range[1]}/{size}")
+        self.send_header("Accept-Ranges", "bytes")
+        self.send_header("Content-Length", str(length))
+        if "etag" in info:
+            self.send_header("ETag", info["etag"])
+        if "mtime" in info:
+            self.send_header("Last-Modified", formatdate(info["mtime"], usegmt=True))
+        self.end_headers()
+        if not body:
+            return
+
+        # Large bodies go out in chunks rather than one buffered read.
+        end = start + length
+        while start < end:
+            chunk = fs.read_range(path, start, min(_CHUNK_SIZE, end - start))
+            if not chunk:
+                break
+            self.wfile.write(chunk)
+            start += len(chunk)
+
+    def log_message(self, format: str, *args: Any) -> None:
+        if self.server.verbose:
+            super().log_message(format, *args)
+
+
+class RangeServer(ThreadingHTTPServer):
+    """Serve ``GET`` and ``HEAD`` with byte ranges for the files of ``fs``
+
+    ``port=0`` picks a free port; see ``url``. Use as a context manager or
+    call ``start()`` and ``stop()`` to run it on a background thread.
+    """
+
+    daemon_threads = True
+
+    def __init__(
+        self,
+        fs: OpendalFileSystem,
+        host: str = "127.0.0.1",
+        port: int = 0,
+        verbose: bool = False,
+    ) -> None:
+        super().__init__((host, port), RangeRequestHandler)
+        self.fs = fs
+        self.verbose = verbose
+        self._thread: threading.Thread | None = None
+
+    @property
+    def url(self) -> str:
+        host, port = self.server_address[:2]
+        return f"http://{host}:{port}"
+
+    def start(self) -> RangeServer:
+        if self._thread is None:
+            self._thread = threading.Thread(
+                target=self.serve_forever, name="opendalfs-http", daemon=True
+            )
+            self._thread.start()
+        return self
+
+    def stop(self) -> None:
+        if self._thread is not None:
+            self.shutdown()
+            self._thread.join()
+            self._thread = None
+        self.server_close()
+
+    def __enter__(self) -> RangeServer:
+        return self.start()
+
+    def __exit__(self, *exc_info: Any) -> None:
+        self.stop()
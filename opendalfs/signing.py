@@ -0,0 +1,51 @@
+from __future__ import annotations
+
+from collections.abc import Mapping
+from urllib.parse import parse_qsl, quote, urlsplit
+
+# Query parameters of a SigV4 presigned URL that carry secrets.
+_SECRET_PARAMS = ("X-Amz-Signature", "X-Amz-Security-Token")
+
+
+def _redact_key(credential: str) -> str:
+    """Keep the first four characters of the access key and the scope"""
+    key, sep, scope = credential.partition("/")
+    return key[:4] + "*" * max(0, len(key) - 4) + sep + scope
+
+
+def _encode(value: str) -> str:
+    return quote(value, safe="-_.~")
+
+
+def signing_details(method: str, url: str, headers: Mapping[str, str]) -> dict[str, str]:
+    """Components of a SigV4 presigned request, with secrets redacted.
+
+    Rebuilds the canonical request an S3-compatible service hashes when it
+    checks the signature, so it can be compared with the one the service
+    reports in a ``SignatureDoesNotMatch`` response. The access key keeps
+    its first four characters; the signature and session token are dropped.
+    """
+    parts = urlsplit(url)
+    query = [
+        (name, _redact_key(value) if name == "X-Amz-Credential" else value)
+        for name, value in parse_qsl(parts.query, keep_blank_values=True)
+        if name not in _SECRET_PARAMS
+    ]
+    params = dict(query)
+    signed = params.get("X-Amz-SignedHeaders", "host")
+    values = {name.lower(): value.strip() for name, value in headers.items()}
+    values.setdefault("host", parts.netloc)
+
+    canonical_query = "&".join(f"{_encode(k)}={_encode(v)}" for k, v in sorted(query))
+    canonical_headers = "".join(f"{name}:{values.get(name, '')}\n" for name in signed.split(";"))
+    canonical = "\n".join(
+        [method, parts.path or "/", canonical_query, canonical_headers, signed, "UNSIGNED-PAYLOAD"]
+    )
+    return {
+        "algorithm": params.get("X-Amz-Algorithm", ""),
+        "credential": params.get("X-Amz-Credential", ""),
+        "date": params.get("X-Amz-Date", ""),
+        "host": values["host"],
+        "signed_headers": signed,
+        "canonical_request": canonical,
+    }
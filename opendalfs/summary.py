@@ -0,0 +1,38 @@
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+
+
+@dataclass
+class TreeSummary:
+    """Aggregate size and age statistics over the files under a prefix.
+
+    ``histogram`` maps a power of two to the number of files smaller than it
+    but at least half its size; empty files count under ``1``.
+    """
+
+    files: int = 0
+    total_size: int = 0
+    min_size: int | None = None
+    max_size: int | None = None
+    oldest_mtime: float | None = None
+    newest_mtime: float | None = None
+    histogram: dict[int, int] = field(default_factory=dict)
+
+    @property
+    def avg_size(self) -> float:
+        return self.total_size / self.files if self.files else 0.0
+
+    def add(self, size: int, mtime: float | None = None) -> None:
+        """Account for one file"""
+        self.files += 1
+        self.total_size += size
+        self.min_size = size if self.min_size is None else min(self.min_size, size)
+        self.max_size = size if self.max_size is None else max(self.max_size, size)
+        bound = 1 << size.bit_length()
+        self.histogram[bound] = self.histogram.get(bound, 0) + 1
+        if mtime is not None:
+            if self.oldest_mtime is None or mtime < self.oldest_mtime:
+                self.oldest_mtime = mtime
+            if self.newest_mtime is None or mtime > self.newest_mtime:
+                self.newest_mtime = mtime
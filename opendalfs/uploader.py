@@ -0,0 +1,126 @@
+from __future__ import annotations
+
+import asyncio
+import concurrent.futures
+import os
+import threading
+from dataclasses import dataclass, field
+from typing import TYPE_CHECKING, Any
+
+if TYPE_CHECKING:
+    from .fs import OpendalFileSystem
+
+# Local files are streamed, so each holds about one write chunk in memory.
+_LOCAL_FILE_COST = 8 * 1024 * 1024
+
+
+@dataclass
+class UploadReport:
+    """Outcome of the uploads submitted to an ``Uploader``"""
+
+    uploaded: list[str] = field(default_factory=list)
+    failed: dict[str, BaseException] = field(default_factory=dict)
+    nbytes: int = 0
+
+    @property
+    def ok(self) -> bool:
+        return not self.failed
+
+
+class Uploader:
+    """Background uploads fed from producer threads.
+
+    ``submit`` queues bytes or a local file for a key and returns at once,
+    unless in-memory data waiting to be uploaded exceeds
+    ``max_pending_bytes``; then it blocks until uploads catch up, so a fast
+    producer cannot outgrow memory. At most ``max_concurrency`` uploads run
+    at a time on the filesystem's event loop. ``join`` waits for everything
+    submitted and reports what failed.
+
+    Call it from synchronous code only: a coroutine on the filesystem's loop
+    blocking in ``submit`` would stall the uploads it waits for.
+    """
+
+    def __init__(
+        self,
+        fs: OpendalFileSystem,
+        max_pending_bytes: int = 256 * 1024 * 1024,
+        max_concurrency: int = 8,
+    ) -> None:
+        if max_pending_bytes < 1 or max_concurrency < 1:
+            raise ValueError("max_pending_bytes and max_concurrency must be positive")
+        self.fs = fs
+        self.max_pending_bytes = max_pending_bytes
+        self._semaphore = asyncio.Semaphore(max_concurrency)
+        self._condition = threading.Condition()
+        self._pending_bytes = 0
+        self._futures: list[concurrent.futures.Future[None]] = []
+        self._report = UploadReport()
+        self._closed = False
+
+    @property
+    def pending_bytes(self) -> int:
+        return self._pending_bytes
+
+    def submit(
+        self, path: str, data: Any = None, lpath: str | os.PathLike[str] | None = None
+    ) -> concurrent.futures.Future[None]:
+        """Queue data, or the local file at lpath, for upload to path"""
+        if (data is None) == (lpath is None):
+            raise ValueError("pass exactly one of data or lpath")
+        if self._closed:
+            raise RuntimeError("uploader is closed")
+
+        if data is not None:
+            nbytes = cost = memoryview(data).nbytes
+        else:
+            nbytes = os.path.getsize(lpath)
+            cost = min(nbytes, _LOCAL_FILE_COST)
+        with self._condition:
+            # A single item above the limit still goes once the queue drains.
+            self._condition.wait_for(
+                lambda: not self._pending_bytes
+                or self._pending_bytes + cost <= self.max_pending_bytes
+            )
+            self._pending_bytes += cost
+
+        future = asyncio.run_coroutine_threadsafe(
+            self._upload(path, data, lpath, nbytes, cost), self.fs.loop
+        )
+        self._futures.append(future)
+        return future
+
+    async def _upload(
+        self, path: str, data: Any, lpath: Any, nbytes: int, cost: int
+    ) -> None:
+        try:
+            async with self._semaphore:
+                if data is not None:
+                    await self.fs._pipe_file(path, data)
+                else:
+                    await self.fs._put_file(os.fspath(lpath), path)
+        except Exception as err:
+            self._report.failed[path] = err
+            raise
+        else:
+            self._report.uploaded.append(path)
+            self._report.nbytes += nbytes
+        finally:
+            with self._condition:
+                self._pending_bytes -= cost
+                self._condition.notify_all()
+
+    def join(self) -> UploadReport:
+        """Wait for every submitted upload and report the outcome"""
+        concurrent.futures.wait(self._futures)
+        return self._report
+
+    def close(self) -> UploadReport:
+        self._closed = True
+        return self.join()
+
+    def __enter__(self) -> Uploader:
+        return self
+
+    def __exit__(self, *exc_info: Any) -> None:
+        self.close()
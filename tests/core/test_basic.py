@@ -1,11 +1,15 @@
 """Core functionality tests for filesystem creation and basic operations."""
 
 import logging
+import os
 
 import pytest
 
 logger = logging.getLogger(__name__)
 
+# Keys present in info dicts only when the backend reports them.
+OPTIONAL_INFO_KEYS = {"etag", "mtime", "is_marker", "content_md5", "version_id"}
+
 
 def test_write_read(s3_fs):
     """Test basic write and read operations."""
@@ -32,13 +36,13 @@ async def test_ls_and_info_fsspec_shape(memory_fs):
     await memory_fs._pipe_file("a/c/d.txt", b"x")
 
     file_info = await memory_fs._info("a/b.txt")
-    assert set(file_info) == {"name", "size", "type"}
+    assert set(file_info) - OPTIONAL_INFO_KEYS == {"name", "size", "type"}
     assert file_info["name"] == "a/b.txt"
     assert file_info["size"] == 5
     assert file_info["type"] == "file"
 
     dir_info = await memory_fs._info("a/")
-    assert set(dir_info) == {"name", "size", "type"}
+    assert set(dir_info) - OPTIONAL_INFO_KEYS == {"name", "size", "type"}
     assert dir_info["name"] == "a/"
     assert dir_info["size"] == 0
     assert dir_info["type"] == "directory"
@@ -47,7 +51,7 @@ async def test_ls_and_info_fsspec_shape(memory_fs):
     assert set(paths) == {"a/b.txt", "a/c/"}
 
     detailed = await memory_fs._ls("a", detail=True)
-    assert all(set(item) == {"name", "size", "type"} for item in detailed)
+    assert all(set(item) - OPTIONAL_INFO_KEYS == {"name", "size", "type"} for item in detailed)
     by_name = {item["name"]: item for item in detailed}
     assert by_name["a/b.txt"]["size"] == 5
     assert by_name["a/b.txt"]["type"] == "file"
@@ -84,3 +88,1514 @@ async def test_invalidate_cache_after_mutations(memory_fs):
     assert "a" not in memory_fs.dircache
     third = await memory_fs._ls("a", detail=True)
     assert {item["name"] for item in third} == {"a/two.txt"}
+
+
+def test_ls_regex(memory_fs):
+    memory_fs.pipe_file("logs/2024-01-01.log", b"a")
+    memory_fs.pipe_file("logs/2024-01-02.txt", b"b")
+    memory_fs.pipe_file("logs/archive/2023-12-31.log", b"c")
+
+    assert sorted(memory_fs.ls_regex("logs", r"\d{4}-\d{2}-\d{2}\.log$")) == [
+        "logs/2024-01-01.log",
+        "logs/archive/2023-12-31.log",
+    ]
+    assert memory_fs.ls_regex("logs", r"\.log$", recursive=False) == ["logs/2024-01-01.log"]
+
+    detailed = memory_fs.ls_regex("logs", r"\.txt$", detail=True)
+    assert [info["name"] for info in detailed] == ["logs/2024-01-02.txt"]
+    assert detailed[0]["size"] == 1
+
+
+def test_root_info_and_listing(memory_fs):
+    memory_fs.pipe_file("top.txt", b"abc")
+    memory_fs.pipe_file("nested/inner.txt", b"x")
+
+    assert memory_fs.info("") == {"name": "", "size": 0, "type": "directory"}
+    assert memory_fs.info("/")["type"] == "directory"
+    assert memory_fs.exists("")
+
+    assert set(memory_fs.ls("", detail=False)) == {"top.txt", "nested/"}
+    by_name = {item["name"]: item for item in memory_fs.ls("")}
+    assert by_name["top.txt"]["size"] == 3
+    assert by_name["nested/"]["type"] == "directory"
+
+
+@pytest.mark.asyncio
+async def test_exists_propagates_permission_errors(memory_fs):
+    from opendal.exceptions import PermissionDenied
+
+    await memory_fs._pipe_file("present.txt", b"x")
+    assert await memory_fs._exists("present.txt")
+    assert not await memory_fs._exists("missing.txt")
+
+    class DeniedOperator:
+        async def stat(self, path):
+            raise PermissionDenied("access denied")
+
+    memory_fs.async_fs = DeniedOperator()
+    with pytest.raises(PermissionError):
+        await memory_fs._exists("present.txt")
+
+
+def test_with_credentials_derives_filesystem(memory_fs):
+    derived = memory_fs.with_credentials(root="/tenant-a")
+
+    assert derived is not memory_fs
+    assert type(derived) is type(memory_fs)
+    assert derived.scheme == "memory"
+    assert derived.storage_options["root"] == "/tenant-a"
+    assert "root" not in memory_fs.storage_options
+
+
+def test_read_callback_reports_timing():
+    from opendalfs import OpendalFileSystem
+
+    events = []
+    fs = OpendalFileSystem(
+        scheme="memory",
+        asynchronous=False,
+        skip_instance_cache=True,
+        read_callback=events.append,
+    )
+    fs.pipe_file("timed.txt", b"0123456789")
+
+    assert fs.cat_file("timed.txt", start=2, end=6) == b"2345"
+    (event,) = events
+    assert event.path == "timed.txt"
+    assert (event.offset, event.size, event.nbytes) == (2, 4, 4)
+    assert event.elapsed >= 0
+
+    with fs.open("timed.txt", "rb") as f:
+        f.read()
+    assert events[-1].nbytes == 10
+
+
+def test_request_log_records_reads():
+    from opendalfs import OpendalFileSystem
+
+    fs = OpendalFileSystem(scheme="memory", skip_instance_cache=True, log_requests=True)
+    fs.pipe_file("logged.bin", b"0123456789")
+
+    fs.cat_file("logged.bin", start=0, end=4)
+    fs.cat_file("logged.bin", start=4)
+    log = fs.request_log(clear=True)
+    assert [(r.path, r.offset, r.nbytes) for r in log] == [
+        ("logged.bin", 0, 4),
+        ("logged.bin", 4, 6),
+    ]
+    assert all(r.elapsed >= 0 for r in log)
+    assert fs.request_log() == []
+
+
+def test_read_range_fetches_only_the_range():
+    from opendalfs import OpendalFileSystem
+
+    fs = OpendalFileSystem(scheme="memory", skip_instance_cache=True, log_requests=True)
+    fs.pipe_file("large.bin", bytes(range(256)) * 64)
+
+    assert fs.read_range("large.bin", 1000, 3) == bytes([1000 % 256, 1001 % 256, 1002 % 256])
+    assert fs.read_range("large.bin", 16380) == bytes([252, 253, 254, 255])
+    assert fs.read_range("large.bin", 5, 0) == b""
+    assert [(r.offset, r.size, r.nbytes) for r in fs.request_log()] == [
+        (1000, 3, 3),
+        (16380, None, 4),
+    ]
+    with pytest.raises(ValueError):
+        fs.read_range("large.bin", -1, 4)
+
+
+def test_request_log_requires_opt_in(memory_fs):
+    with pytest.raises(RuntimeError):
+        memory_fs.request_log()
+
+
+def test_pipe_file_buffer_protocol(memory_fs):
+    from array import array
+
+    memory_fs.pipe_file("bytearray.bin", bytearray(b"abc"))
+    assert memory_fs.cat_file("bytearray.bin") == b"abc"
+
+    memory_fs.pipe_file("view.bin", memoryview(b"0123456789")[2:6])
+    assert memory_fs.cat_file("view.bin") == b"2345"
+
+    values = array("i", [1, 2, 3])
+    memory_fs.pipe_file("array.bin", values)
+    assert memory_fs.cat_file("array.bin") == values.tobytes()
+
+    strided = memoryview(b"abcdef")[::2]
+    memory_fs.pipe_file("strided.bin", strided)
+    assert memory_fs.cat_file("strided.bin") == b"ace"
+
+    memory_fs.pipe_file("empty.bin", bytearray())
+    assert memory_fs.cat_file("empty.bin") == b""
+
+
+def test_put_stream_sources(memory_fs):
+    import io
+
+    assert memory_fs.put_stream("from-file.bin", io.BytesIO(b"abcdefgh"), chunk_size=3) == 8
+    assert memory_fs.cat_file("from-file.bin") == b"abcdefgh"
+
+    def generate():
+        for i in range(4):
+            yield str(i).encode() * 2
+
+    assert memory_fs.put_stream("from-iter.bin", generate()) == 8
+    assert memory_fs.cat_file("from-iter.bin") == b"00112233"
+
+    assert memory_fs.put_stream("empty.bin", iter(())) == 0
+    assert memory_fs.cat_file("empty.bin") == b""
+
+
+@pytest.mark.asyncio
+async def test_put_stream_async_iterable(memory_fs):
+    async def generate():
+        yield b"hello "
+        yield bytearray(b"world")
+
+    assert await memory_fs._put_stream("async.bin", generate()) == 11
+    assert await memory_fs._cat_file("async.bin") == b"hello world"
+
+
+def test_write_stream_slices_buffers(memory_fs):
+    from array import array
+
+    writes = []
+    memory_fs._before_operation = lambda operation, path: writes.append(operation)
+
+    data = array("I", range(10))
+    assert memory_fs.write_stream("ints.bin", data, chunk_size=8) == 40
+    assert memory_fs.cat_file("ints.bin") == data.tobytes()
+    # One writer for the whole upload rather than one PUT per slice.
+    assert writes.count("open") == 1
+    assert "write" not in writes
+
+
+def test_readinto_at(memory_fs):
+    from array import array
+
+    memory_fs.pipe_file("into.bin", b"0123456789")
+
+    buf = bytearray(4)
+    assert memory_fs.readinto_at("into.bin", 3, buf) == 4
+    assert buf == b"3456"
+
+    ints = array("b", [0] * 2)
+    assert memory_fs.readinto_at("into.bin", 8, ints) == 2
+    assert ints.tobytes() == b"89"
+
+    with pytest.raises(TypeError):
+        memory_fs.readinto_at("into.bin", 0, b"readonly")
+
+
+@pytest.mark.asyncio
+async def test_per_call_retries_and_timeout(memory_fs):
+    import asyncio
+
+    from opendal.exceptions import Unexpected
+
+    class FlakyOperator:
+        def __init__(self):
+            self.calls = 0
+
+        async def read(self, path, **kwargs):
+            self.calls += 1
+            if self.calls == 1:
+                raise Unexpected("connection reset")
+            return b"data"
+
+    memory_fs.async_fs = FlakyOperator()
+    with pytest.raises(Unexpected):
+        await memory_fs._cat_file("flaky.txt")
+
+    memory_fs.async_fs = FlakyOperator()
+    assert await memory_fs._cat_file("flaky.txt", retries=1) == b"data"
+    assert memory_fs.async_fs.calls == 2
+
+    class SlowOperator:
+        async def read(self, path, **kwargs):
+            await asyncio.sleep(10)
+            return b""
+
+    memory_fs.async_fs = SlowOperator()
+    with pytest.raises(TimeoutError):
+        await memory_fs._cat_file("slow.txt", timeout=0.01)
+
+
+@pytest.mark.asyncio
+async def test_timeouts_bound_metadata_calls_and_stream_steps(memory_fs):
+    import asyncio
+
+    from opendalfs.operator import HookedOperator
+
+    class StalledReader:
+        async def read(self, size=None):
+            await asyncio.sleep(10)
+            return b""
+
+        async def close(self):
+            pass
+
+    class StalledOperator:
+        async def stat(self, path):
+            await asyncio.sleep(10)
+
+        async def open(self, path, mode):
+            return StalledReader()
+
+    memory_fs.timeout = 0.01
+    memory_fs.async_fs = HookedOperator(memory_fs, StalledOperator(), True)
+    with pytest.raises(TimeoutError):
+        await memory_fs.async_fs.stat("hung.txt")
+
+    memory_fs.timeout = None
+    memory_fs.io_timeout = 0.01
+    reader = await memory_fs.async_fs.open("hung.txt", "rb")
+    with pytest.raises(TimeoutError):
+        await reader.read(1024)
+
+
+@pytest.mark.asyncio
+async def test_timeout_bounds_calls_made_outside_call(memory_fs):
+    import asyncio
+
+    from opendalfs.operator import HookedOperator
+
+    class SlowOperator:
+        async def delete(self, path):
+            await asyncio.sleep(0.1)
+
+    memory_fs.timeout = 0.01
+    memory_fs.async_fs = HookedOperator(memory_fs, SlowOperator(), True)
+    with pytest.raises(TimeoutError):
+        await memory_fs._rm_file("hung.txt")
+
+    # A longer per-call timeout is not cut short by the filesystem's own.
+    await memory_fs._call(lambda: memory_fs.async_fs.delete("slow.txt"), timeout=5)
+
+
+@pytest.mark.asyncio
+async def test_timeout_bounds_each_page_of_a_listing(memory_fs):
+    import asyncio
+    from types import SimpleNamespace
+
+    from opendalfs.operator import HookedOperator
+
+    class SlowLister:
+        def __init__(self):
+            self.names = [f"dir/{i}.txt" for i in range(5)]
+
+        def __aiter__(self):
+            return self
+
+        async def __anext__(self):
+            if not self.names:
+                raise StopAsyncIteration
+            await asyncio.sleep(0.02)
+            return SimpleNamespace(path=self.names.pop(0), metadata=None)
+
+    class SlowOperator:
+        async def list(self, path, **kwargs):
+            return SlowLister()
+
+    memory_fs.async_fs = HookedOperator(memory_fs, SlowOperator(), True)
+    # The listing as a whole takes longer than the timeout, each page does not.
+    names = await memory_fs._ls("dir", detail=False, timeout=0.05)
+    assert names == [f"dir/{i}.txt" for i in range(5)]
+
+    memory_fs.timeout = 0.01
+    with pytest.raises(TimeoutError):
+        await memory_fs._ls("dir", detail=False, refresh=True)
+
+
+def test_case_insensitive_lookup():
+    from opendalfs import OpendalFileSystem
+
+    fs = OpendalFileSystem(
+        scheme="memory",
+        asynchronous=False,
+        skip_instance_cache=True,
+        case_insensitive=True,
+    )
+    fs.pipe_file("Share/Reports/Q1.CSV", b"totals")
+
+    assert fs.cat_file("share/reports/q1.csv") == b"totals"
+    assert fs.info("SHARE/REPORTS/q1.csv")["size"] == 6
+    assert fs.exists("share/Reports/Q1.csv")
+    assert not fs.exists("share/reports/q2.csv")
+    with pytest.raises(FileNotFoundError):
+        fs.info("share/missing.csv")
+
+
+def test_trash_mode_moves_instead_of_deleting():
+    from opendalfs import OpendalFileSystem
+
+    fs = OpendalFileSystem(scheme="memory", trash_prefix=".trash", skip_instance_cache=True)
+    fs.pipe_file("data/a.txt", b"a")
+    fs.pipe_file("data/sub/b.txt", b"b")
+
+    fs.rm("data", recursive=True)
+    assert not fs.exists("data/a.txt")
+
+    (folder,) = fs.ls(".trash", detail=False)
+    assert fs.cat_file(f"{folder.rstrip('/')}/data/a.txt") == b"a"
+    assert fs.cat_file(f"{folder.rstrip('/')}/data/sub/b.txt") == b"b"
+
+    # Too recent to be emptied yet.
+    assert fs.empty_trash(older_than=3600) == []
+    assert fs.empty_trash() == [folder.rstrip("/")]
+    assert not fs.exists(f"{folder.rstrip('/')}/data/a.txt")
+
+
+def test_empty_trash_requires_trash_mode(memory_fs):
+    with pytest.raises(ValueError):
+        memory_fs.empty_trash()
+
+
+@pytest.mark.asyncio
+async def test_mtime_keeps_sub_second_precision(memory_fs):
+    from datetime import datetime, timezone
+
+    stamp = datetime(2024, 5, 1, 12, 30, 15, 123456, tzinfo=timezone.utc)
+
+    class Mode:
+        def is_dir(self):
+            return False
+
+        def is_file(self):
+            return True
+
+    class Metadata:
+        content_length = 3
+        mode = Mode()
+        etag = None
+        last_modified = stamp
+
+    class StampedOperator:
+        async def stat(self, path):
+            return Metadata()
+
+    memory_fs.async_fs = StampedOperator()
+
+    info = await memory_fs._info("f.txt")
+    assert info["mtime"] == stamp.timestamp()
+    assert info["mtime"] % 1 != 0
+    assert await memory_fs._modified("f.txt") == stamp
+    with pytest.raises(NotImplementedError):
+        await memory_fs._created("f.txt")
+
+    Metadata.created = stamp
+    assert await memory_fs._created("f.txt") == stamp
+
+
+@pytest.mark.asyncio
+async def test_directory_markers_are_directories(memory_fs):
+    class Mode:
+        def __init__(self, is_dir):
+            self._is_dir = is_dir
+
+        def is_dir(self):
+            return self._is_dir
+
+        def is_file(self):
+            return not self._is_dir
+
+    class Metadata:
+        def __init__(self, is_dir, etag=None):
+            self.content_length = 0
+            self.mode = Mode(is_dir)
+            self.etag = etag
+            self.last_modified = None
+
+    objects = {
+        "marker/": Metadata(is_dir=False, etag='"d41d8cd9"'),
+        "prefix/": Metadata(is_dir=True),
+        "empty.txt": Metadata(is_dir=False, etag='"d41d8cd9"'),
+    }
+
+    class MarkerOperator:
+        async def stat(self, path):
+            return objects[path]
+
+    memory_fs.async_fs = MarkerOperator()
+
+    marker = await memory_fs._info("marker/")
+    assert (marker["type"], marker["is_marker"]) == ("directory", True)
+
+    prefix = await memory_fs._info("prefix/")
+    assert (prefix["type"], prefix["is_marker"]) == ("directory", False)
+
+    empty = await memory_fs._info("empty.txt")
+    assert empty["type"] == "file"
+    assert "is_marker" not in empty
+
+
+@pytest.mark.asyncio
+async def test_update_metadata_rewrites_in_place(memory_fs):
+    class Metadata:
+        content_type = "application/octet-stream"
+        cache_control = "max-age=60"
+        content_disposition = None
+        content_encoding = "gzip"
+        user_metadata = {"owner": "etl"}
+
+    writes = {}
+
+    class Reader:
+        def __init__(self, path):
+            self.data = f"data:{path}".encode()
+
+        async def read(self, size=None):
+            data, self.data = self.data, b""
+            return data
+
+        async def close(self):
+            pass
+
+    class Writer:
+        def __init__(self, path, options):
+            self.path = path
+            self.options = options
+            self.data = b""
+
+        async def write(self, chunk):
+            self.data += chunk
+
+        async def close(self):
+            writes[self.path] = (self.data, self.options)
+
+    class RecordingOperator:
+        async def stat(self, path):
+            return Metadata()
+
+        async def open(self, path, mode, **options):
+            return Reader(path) if mode == "rb" else Writer(path, options)
+
+    memory_fs.async_fs = RecordingOperator()
+
+    updated = await memory_fs._update_metadata(["a.csv", "b.csv"], content_type="text/csv")
+
+    assert sorted(updated) == ["a.csv", "b.csv"]
+    assert writes["a.csv"] == (
+        b"data:a.csv",
+        {
+            "content_type": "text/csv",
+            "cache_control": "max-age=60",
+            "content_encoding": "gzip",
+            "user_metadata": {"owner": "etl"},
+        },
+    )
+
+    await memory_fs._update_metadata("a.csv", metadata={"owner": "ops"})
+    assert writes["a.csv"][1]["user_metadata"] == {"owner": "ops"}
+    assert writes["a.csv"][1]["content_type"] == "application/octet-stream"
+
+
+def test_update_metadata_requires_changes(memory_fs):
+    with pytest.raises(ValueError):
+        memory_fs.update_metadata("a.csv")
+
+
+@pytest.mark.asyncio
+async def test_list_page_size_is_passed_to_lister(memory_fs):
+    calls = []
+
+    class Entry:
+        def __init__(self, path):
+            self.path = path
+
+    class PagingOperator:
+        async def list(self, path, **kwargs):
+            calls.append(kwargs)
+
+            async def entries():
+                yield Entry("dir/a.txt")
+
+            return entries()
+
+    memory_fs.async_fs = PagingOperator()
+
+    assert await memory_fs._ls("dir", detail=False) == ["dir/a.txt"]
+    memory_fs.list_page_size = 500
+    await memory_fs._ls("dir", detail=False)
+    await memory_fs._ls("dir", detail=False, list_page_size=10)
+
+    assert calls == [{}, {"limit": 500}, {"limit": 10}]
+
+
+class _FlakyReader:
+    """Serves data from a shared dict, failing after ``fail_after`` bytes"""
+
+    def __init__(self, store, path, fail_after):
+        self.store = store
+        self.path = path
+        self.position = 0
+        self.fail_after = fail_after
+
+    async def seek(self, position):
+        self.position = position
+
+    async def read(self, size):
+        from opendal.exceptions import Unexpected
+
+        if self.fail_after is not None and self.position >= self.fail_after:
+            raise Unexpected("connection reset")
+        data = self.store["data"][self.position : self.position + min(size, 4)]
+        self.position += len(data)
+        return data
+
+    async def close(self):
+        pass
+
+
+def _resumable_operator(store, fail_after):
+    from opendal.exceptions import ConditionNotMatch
+
+    class Metadata:
+        def __init__(self):
+            self.content_length = len(store["data"])
+            self.etag = store["etag"]
+
+    class Capability:
+        read_with_if_match = True
+
+    class ResumableOperator:
+        opened = 0
+        stats = 0
+
+        def capability(self):
+            return Capability()
+
+        async def stat(self, path):
+            ResumableOperator.stats += 1
+            return Metadata()
+
+        async def open(self, path, mode, if_match=None):
+            if if_match is not None and if_match != store["etag"]:
+                raise ConditionNotMatch("etag changed")
+            ResumableOperator.opened += 1
+            # Only the first connection drops.
+            return _FlakyReader(store, path, fail_after if ResumableOperator.opened == 1 else None)
+
+    return ResumableOperator()
+
+
+@pytest.mark.asyncio
+async def test_resumable_read_continues_from_last_byte(memory_fs):
+    store = {"data": b"0123456789abcdef", "etag": '"v1"'}
+    memory_fs.resume_reads = 2
+    memory_fs.async_fs = _resumable_operator(store, fail_after=6)
+
+    assert await memory_fs._cat_file("big.bin") == b"0123456789abcdef"
+    assert memory_fs.async_fs.stats == 1
+
+    # Reads that do not fail send no HEAD.
+    assert await memory_fs._cat_file("big.bin", start=3, end=9) == b"345678"
+    assert memory_fs.async_fs.stats == 1
+
+
+@pytest.mark.asyncio
+async def test_resumable_read_detects_replaced_object(memory_fs):
+    from opendalfs import FileChangedError
+
+    store = {"data": b"0123456789abcdef", "etag": '"v1"'}
+    memory_fs.resume_reads = 2
+    memory_fs.async_fs = _resumable_operator(store, fail_after=6)
+
+    original_stat = memory_fs.async_fs.stat
+
+    async def stat_then_replace(path):
+        metadata = await original_stat(path)
+        store["etag"] = '"v2"'
+        return metadata
+
+    memory_fs.async_fs.stat = stat_then_replace
+    with pytest.raises(FileChangedError):
+        await memory_fs._cat_file("big.bin")
+
+
+def test_blocking_mode_serves_sync_and_async_apis():
+    from opendalfs import OpendalFileSystem
+
+    fs = OpendalFileSystem(scheme="memory", blocking=True, skip_instance_cache=True)
+    fs.pipe_file("dir/a.txt", b"hello")
+
+    assert fs.cat_file("dir/a.txt", start=1) == b"ello"
+    assert fs.ls("dir", detail=False) == ["dir/a.txt"]
+    with fs.open("dir/b.txt", "wb") as f:
+        f.write(b"world")
+    with fs.open("dir/b.txt", "rb") as f:
+        assert f.read() == b"world"
+
+
+@pytest.mark.asyncio
+async def test_blocking_mode_async_file_roundtrip():
+    from opendalfs import OpendalFileSystem
+
+    fs = OpendalFileSystem(
+        scheme="memory", blocking=True, asynchronous=True, skip_instance_cache=True
+    )
+    fs.multipart_threshold = 0  # go through the threaded writer
+    async with await fs.open_async("stream.bin", "wb") as f:
+        await f.write(b"chunked")
+    async with await fs.open_async("stream.bin", "rb") as f:
+        assert await f.read() == b"chunked"
+
+
+def test_blocking_mode_runs_on_own_worker_threads():
+    import threading
+
+    from opendalfs import OpendalFileSystem
+
+    fs = OpendalFileSystem(
+        scheme="memory",
+        blocking=True,
+        worker_threads=2,
+        thread_name="arrow-io",
+        skip_instance_cache=True,
+    )
+    threads = []
+    inner = fs.async_fs.inner._operator
+
+    class RecordingOperator:
+        def __getattr__(self, name):
+            attr = getattr(inner, name)
+            if name == "capability" or not callable(attr):
+                return attr
+
+            def call(*args, **kwargs):
+                threads.append(threading.current_thread().name)
+                return attr(*args, **kwargs)
+
+            return call
+
+    fs.async_fs.inner._operator = RecordingOperator()
+    fs.pipe_file("a.txt", b"hello")
+    assert fs.cat_file("a.txt") == b"hello"
+
+    assert threads
+    assert all(name.startswith("arrow-io") for name in threads)
+
+
+def test_blocking_worker_threads_stop_with_their_filesystem():
+    import gc
+
+    from opendalfs import OpendalFileSystem
+
+    fs = OpendalFileSystem(
+        scheme="memory", blocking=True, worker_threads=2, skip_instance_cache=True
+    )
+    fs.pipe_file("a.txt", b"hello")
+    executor = fs._executor
+
+    del fs
+    gc.collect()
+    assert executor._shutdown
+
+
+def test_key_too_long_is_rejected_before_upload(memory_fs):
+    from opendalfs import KeyTooLongError
+
+    memory_fs.max_key_length = 32
+    long_key = "deep/" * 6 + "ключ.txt"
+    with pytest.raises(KeyTooLongError) as excinfo:
+        memory_fs.pipe_file(long_key, b"data")
+    assert excinfo.value.length > 32
+    with pytest.raises(KeyTooLongError):
+        memory_fs.open(long_key, "wb")
+    assert not memory_fs.exists(long_key)
+
+    memory_fs.pipe_file("short.txt", b"ok")
+    assert memory_fs.cat_file("short.txt") == b"ok"
+
+
+@pytest.mark.asyncio
+async def test_listing_is_normalized(memory_fs):
+    class Entry:
+        def __init__(self, path):
+            self.path = path
+
+    class UnorderedOperator:
+        async def list(self, path, **kwargs):
+            async def entries():
+                for name in ("dir/", "dir/b.txt", "dir/a.txt", "dir/b.txt", "dir/sub/"):
+                    yield Entry(name)
+
+            return entries()
+
+    memory_fs.async_fs = UnorderedOperator()
+    assert await memory_fs._ls("dir", detail=False) == ["dir/a.txt", "dir/b.txt", "dir/sub/"]
+
+    memory_fs.sort_listings = False
+    assert await memory_fs._ls("dir", detail=False) == ["dir/b.txt", "dir/a.txt", "dir/sub/"]
+
+
+def test_cat_ranges_reads_concurrently(memory_fs):
+    memory_fs.pipe_file("ranges/a.bin", b"0123456789")
+    memory_fs.pipe_file("ranges/b.bin", b"abcdefghij")
+
+    result = memory_fs.cat_ranges(
+        ["ranges/a.bin", "ranges/b.bin", "ranges/a.bin", "ranges/missing.bin"],
+        [0, 2, 7, 0],
+        [3, 5, None, 1],
+        batch_size=2,
+    )
+    assert result[:3] == [b"012", b"cde", b"789"]
+    assert isinstance(result[3], Exception)
+
+    with pytest.raises(ValueError):
+        memory_fs.cat_ranges(["ranges/a.bin"], [0, 1], [2, 3])
+
+
+def _paging_operator(names, fail_after):
+    from opendal.exceptions import Unexpected
+
+    class Entry:
+        def __init__(self, path):
+            self.path = path
+
+    class InterruptedOperator:
+        calls = []
+
+        async def list(self, path, **kwargs):
+            InterruptedOperator.calls.append(kwargs)
+            start_after = kwargs.get("start_after")
+            remaining = [n for n in names if start_after is None or n > start_after]
+            fail = len(InterruptedOperator.calls) == 1
+
+            async def entries():
+                for i, name in enumerate(remaining):
+                    if fail and i == fail_after:
+                        raise Unexpected("connection reset")
+                    yield Entry(name)
+
+            return entries()
+
+    return InterruptedOperator()
+
+
+@pytest.mark.asyncio
+async def test_interrupted_listing_can_be_resumed(memory_fs):
+    from opendalfs import ListingInterrupted
+
+    names = [f"big/{i:03d}" for i in range(6)]
+    memory_fs.async_fs = _paging_operator(names, fail_after=4)
+
+    with pytest.raises(ListingInterrupted) as excinfo:
+        await memory_fs._ls("big", detail=False)
+    err = excinfo.value
+    assert err.entries == names[:4]
+
+    rest = await memory_fs._ls("big", detail=False, start_after=err.start_after)
+    assert err.entries + rest == names
+
+
+@pytest.mark.asyncio
+async def test_listing_retries_resume_after_last_entry(memory_fs):
+    names = [f"big/{i:03d}" for i in range(6)]
+    operator = _paging_operator(names, fail_after=2)
+    memory_fs.async_fs = operator
+
+    assert await memory_fs._ls("big", detail=False, retries=1) == names
+    assert operator.calls[1] == {"start_after": "big/001"}
+
+
+def test_exists_with_deadline_returns_unknown(memory_fs):
+    import asyncio
+
+    memory_fs.pipe_file("present.txt", b"x")
+    assert memory_fs.exists("present.txt", timeout=5) is True
+    assert memory_fs.exists("absent.txt", timeout=5) is False
+
+    class SlowOperator:
+        async def stat(self, path):
+            await asyncio.sleep(5)
+
+    memory_fs.async_fs = SlowOperator()
+    assert memory_fs.exists("present.txt", timeout=0.05) is None
+
+
+def test_summary_aggregates_tree(memory_fs):
+    memory_fs.pipe_file("tree/a.bin", b"")
+    memory_fs.pipe_file("tree/b.bin", b"x" * 3)
+    memory_fs.pipe_file("tree/sub/c.bin", b"x" * 100)
+
+    summary = memory_fs.summary("tree")
+    assert summary.files == 3
+    assert summary.total_size == 103
+    assert (summary.min_size, summary.max_size) == (0, 100)
+    assert summary.avg_size == pytest.approx(103 / 3)
+    assert summary.histogram == {1: 1, 4: 1, 128: 1}
+
+    assert memory_fs.summary("missing").files == 0
+    assert memory_fs.summary("tree/b.bin").total_size == 3
+
+
+def test_cp_file_uses_native_copy_only_when_supported(memory_fs):
+    memory_fs.pipe_file("copy/src.bin", b"payload" * 1000)
+    ops = []
+    memory_fs._before_operation = lambda operation, path: ops.append(operation)
+
+    memory_fs.cp_file("copy/src.bin", "copy/dst.bin")
+
+    assert memory_fs.cat_file("copy/dst.bin") == b"payload" * 1000
+    native = memory_fs.operator.capability().copy
+    assert ("copy" in ops) == native
+    if not native:
+        assert "open" in ops
+
+
+def test_mv_recursive_moves_directory(memory_fs):
+    memory_fs.pipe_file("olddir/a.txt", b"a")
+    memory_fs.pipe_file("olddir/sub/b.txt", b"b")
+    ops = []
+    memory_fs._before_operation = lambda operation, path: ops.append(operation)
+
+    memory_fs.mv("olddir", "newdir", recursive=True)
+
+    assert not memory_fs.exists("olddir/a.txt")
+    assert memory_fs.cat_file("newdir/a.txt") == b"a"
+    assert memory_fs.cat_file("newdir/sub/b.txt") == b"b"
+    if memory_fs.operator.capability().rename:
+        assert ops.count("rename") == 2
+        assert "read" not in ops
+
+
+def test_mv_keeps_directory_markers(memory_fs):
+    memory_fs.pipe_file("olddir/a.txt", b"a")
+    memory_fs.mkdir("olddir/empty")
+
+    memory_fs.mv("olddir", "newdir", recursive=True)
+
+    assert memory_fs.isdir("newdir/empty")
+    assert memory_fs.find("olddir", withdirs=True) == []
+
+
+def test_mv_to_same_path_keeps_file(memory_fs):
+    memory_fs.pipe_file("same.txt", b"data")
+    memory_fs.mv("same.txt", "same.txt")
+    memory_fs.mv("same.txt", "same.txt", recursive=True, maxdepth=1)
+    assert memory_fs.cat_file("same.txt") == b"data"
+
+
+def test_mv_fallback_keeps_sources_whose_copy_failed(memory_fs):
+    memory_fs.pipe_file("src/a.txt", b"a")
+    memory_fs.pipe_file("src/b.txt", b"b")
+    original = memory_fs._cp_file
+
+    async def flaky_copy(path1, path2, **kwargs):
+        if path1.endswith("b.txt"):
+            raise FileNotFoundError(path1)
+        await original(path1, path2, **kwargs)
+
+    memory_fs._cp_file = flaky_copy
+    with pytest.raises(FileNotFoundError):
+        # maxdepth takes the copy-and-delete path.
+        memory_fs.mv("src", "dst", recursive=True, maxdepth=5)
+    assert memory_fs.cat_file("src/b.txt") == b"b"
+
+
+def test_mv_of_several_files_needs_no_emulation_with_rename(tmp_path):
+    from opendalfs import OpendalFileSystem
+
+    fs = OpendalFileSystem(
+        scheme="fs", root=str(tmp_path), emulate_missing=False, skip_instance_cache=True
+    )
+    fs.pipe_file("a.txt", b"a")
+    fs.pipe_file("b.txt", b"b")
+
+    fs.mv(["a.txt", "b.txt"], "out/")
+
+    assert sorted(os.listdir(tmp_path / "out")) == ["a.txt", "b.txt"]
+    assert not fs.exists("a.txt")
+
+
+def test_reconfigure_swaps_operator_in_place(memory_fs):
+    memory_fs.pipe_file("before.txt", b"x")
+    assert memory_fs.ls("", detail=False) == ["before.txt"]
+    old_operator = memory_fs.operator
+
+    memory_fs.reconfigure(root="/rotated")
+
+    assert memory_fs.operator is not old_operator
+    assert memory_fs.storage_options["root"] == "/rotated"
+    # The listing cache went with the old operator.
+    assert not memory_fs.exists("before.txt")
+    memory_fs.pipe_file("after.txt", b"y")
+    assert memory_fs.cat_file("after.txt") == b"y"
+
+
+def test_find_uses_one_recursive_listing(memory_fs):
+    for key in ("deep/a.txt", "deep/x/b.txt", "deep/x/y/c.txt"):
+        memory_fs.pipe_file(key, b"data")
+    ops = []
+    memory_fs._before_operation = lambda operation, path: ops.append(operation)
+
+    assert memory_fs.find("deep") == ["deep/a.txt", "deep/x/b.txt", "deep/x/y/c.txt"]
+    assert ops.count("list") + ops.count("scan") == 1
+
+    assert memory_fs.find("deep", maxdepth=2) == ["deep/a.txt", "deep/x/b.txt"]
+    assert memory_fs.find("deep", withdirs=True, maxdepth=2) == [
+        "deep",
+        "deep/a.txt",
+        "deep/x",
+        "deep/x/b.txt",
+        "deep/x/y",
+    ]
+    detail = memory_fs.find("deep", detail=True, withdirs=True)
+    assert detail["deep/x"]["type"] == "directory"
+    assert detail["deep/x/y/c.txt"]["size"] == 4
+    assert memory_fs.find("deep/a.txt") == ["deep/a.txt"]
+
+
+def test_ls_sharded_matches_find(memory_fs):
+    keys = [f"inventory/{p}/{q}/obj-{i}" for p in "abc" for q in "xy" for i in range(3)]
+    keys.append("inventory/top.txt")
+    for key in keys:
+        memory_fs.pipe_file(key, b"1")
+
+    assert memory_fs.ls_sharded("inventory", shards=4) == sorted(keys)
+    assert memory_fs.ls_sharded("inventory", shards=1) == memory_fs.find("inventory")
+
+    detail = memory_fs.ls_sharded("inventory", shards=4, detail=True)
+    assert detail["inventory/top.txt"]["size"] == 1
+    assert memory_fs.ls_sharded("missing") == []
+
+
+@pytest.mark.asyncio
+async def test_ls_detail_uses_listed_metadata(memory_fs):
+    from datetime import datetime, timezone
+
+    class Mode:
+        def is_dir(self):
+            return False
+
+        def is_file(self):
+            return True
+
+    class Metadata:
+        content_length = 42
+        mode = Mode()
+        etag = '"abc"'
+        last_modified = datetime(2024, 1, 1, tzinfo=timezone.utc)
+
+    class Entry:
+        def __init__(self, path):
+            self.path = path
+            self.metadata = Metadata()
+
+    class Capability:
+        list_has_content_length = True
+
+    class ListingOperator:
+        def capability(self):
+            return Capability()
+
+        async def list(self, path, **kwargs):
+            async def entries():
+                yield Entry("meta/a.bin")
+
+            return entries()
+
+        async def stat(self, path):
+            raise AssertionError("ls should not stat listed entries")
+
+    memory_fs.async_fs = ListingOperator()
+
+    [info] = await memory_fs._ls("meta", detail=True)
+    assert info == {
+        "name": "meta/a.bin",
+        "size": 42,
+        "type": "file",
+        "etag": '"abc"',
+        "mtime": Metadata.last_modified.timestamp(),
+    }
+
+
+def test_ls_iter_streams_entries(memory_fs):
+    for i in range(5):
+        memory_fs.pipe_file(f"stream/{i}.txt", b"x" * i)
+
+    entries = memory_fs.ls_iter("stream")
+    first = next(entries)
+    assert sorted([first, *entries]) == memory_fs.ls("stream", detail=False)
+
+    sizes = {info["name"]: info["size"] for info in memory_fs.ls_iter("stream", detail=True)}
+    assert sizes["stream/4.txt"] == 4
+
+
+@pytest.mark.asyncio
+async def test_ls_iter_async():
+    from opendalfs import OpendalFileSystem
+
+    fs = OpendalFileSystem(scheme="memory", asynchronous=True, skip_instance_cache=True)
+    await fs._pipe_file("stream/a.txt", b"a")
+
+    assert [name async for name in fs._ls_iter("stream")] == ["stream/a.txt"]
+
+
+@pytest.mark.asyncio
+async def test_presign_returns_url_and_headers(memory_fs):
+    calls = []
+
+    class Capability:
+        presign = True
+
+    class Request:
+        method = "PUT"
+        url = "https://bucket.example.com/upload.bin?X-Amz-Signature=abc"
+        headers = {"host": "bucket.example.com"}
+
+    class PresigningOperator:
+        def capability(self):
+            return Capability()
+
+        async def presign_write(self, path, expire_second):
+            calls.append((path, expire_second))
+            return Request()
+
+    memory_fs.async_fs = PresigningOperator()
+
+    signed = await memory_fs._presign("upload.bin", expiration=300, method="put")
+    assert signed.url == Request.url
+    assert signed.headers == {"host": "bucket.example.com"}
+    assert calls == [("upload.bin", 300)]
+
+    with pytest.raises(ValueError):
+        await memory_fs._presign("upload.bin", method="PATCH")
+
+
+def test_sign_unsupported_backend(memory_fs):
+    from opendalfs import CapabilityError
+
+    with pytest.raises(NotImplementedError):
+        memory_fs.sign("file.txt")
+    with pytest.raises(CapabilityError) as excinfo:
+        memory_fs.presign("file.txt")
+    assert excinfo.value.capability == "presign"
+    assert excinfo.value.alternative is None
+
+
+@pytest.mark.asyncio
+async def test_info_and_checksum_expose_content_hashes(memory_fs):
+    class Mode:
+        def is_dir(self):
+            return False
+
+        def is_file(self):
+            return True
+
+    class Metadata:
+        content_length = 3
+        mode = Mode()
+        etag = '"abc"'
+        content_md5 = "kAFQmDzST7DWlj99KOF/cg=="
+        version = "v42"
+        last_modified = None
+
+    class VersionedOperator:
+        async def stat(self, path):
+            return Metadata()
+
+    memory_fs.async_fs = VersionedOperator()
+
+    info = await memory_fs._info("hashed.bin")
+    assert info["content_md5"] == "kAFQmDzST7DWlj99KOF/cg=="
+    assert info["version_id"] == "v42"
+    from_md5 = await memory_fs._checksum("hashed.bin")
+    assert isinstance(from_md5, int)
+
+    # A new version under the same MD5 keeps the checksum; the ETag is used without one.
+    Metadata.version = "v43"
+    assert await memory_fs._checksum("hashed.bin") == from_md5
+    Metadata.content_md5 = None
+    from_etag = await memory_fs._checksum("hashed.bin")
+    assert isinstance(from_etag, int) and from_etag != from_md5
+    Metadata.etag = '"def"'
+    assert await memory_fs._checksum("hashed.bin") != from_etag
+
+
+@pytest.mark.asyncio
+async def test_pipe_file_create_uses_conditional_write(memory_fs):
+    from opendal.exceptions import ConditionNotMatch
+
+    writes = []
+
+    class Capability:
+        write_with_if_not_exists = True
+
+    class ConditionalOperator:
+        def capability(self):
+            return Capability()
+
+        async def stat(self, path):
+            raise AssertionError("create should not probe with a HEAD")
+
+        async def write(self, path, data, **kwargs):
+            writes.append((path, data, kwargs))
+            if path == "taken.bin":
+                raise ConditionNotMatch("exists")
+
+    memory_fs.async_fs = ConditionalOperator()
+
+    await memory_fs._pipe_file("new.bin", bytearray(b"data"), mode="create")
+    assert writes == [("new.bin", b"data", {"if_not_exists": True})]
+
+    with pytest.raises(FileExistsError):
+        await memory_fs._pipe_file("taken.bin", b"data", mode="create")
+
+    # Skipping the HEAD keeps the condition, which costs no extra request.
+    with pytest.raises(FileExistsError):
+        await memory_fs._pipe_file("taken.bin", b"data", mode="create", check_exists=False)
+    assert writes[-1] == ("taken.bin", b"data", {"if_not_exists": True})
+
+
+def test_has_changed_tokens(memory_fs):
+    changed, token = memory_fs.has_changed("polled.txt", None)
+    assert (changed, token) == (False, None)
+
+    memory_fs.pipe_file("polled.txt", b"v1")
+    changed, token = memory_fs.has_changed("polled.txt", token)
+    assert changed and token is not None
+
+    assert memory_fs.has_changed("polled.txt", token) == (False, token)
+
+    memory_fs.pipe_file("polled.txt", b"version 2")
+    changed, token = memory_fs.has_changed("polled.txt", token)
+    assert changed
+
+    memory_fs.rm_file("polled.txt")
+    assert memory_fs.has_changed("polled.txt", token) == (True, None)
+
+
+@pytest.mark.asyncio
+async def test_du_sums_listed_sizes(memory_fs):
+    class Metadata:
+        def __init__(self, size):
+            self.content_length = size
+
+    class Entry:
+        def __init__(self, path, size):
+            self.path = path
+            self.metadata = Metadata(size)
+
+    class Capability:
+        list_has_content_length = True
+
+    class SizedOperator:
+        def capability(self):
+            return Capability()
+
+        async def list(self, path, **kwargs):
+            assert kwargs == {"recursive": True}
+
+            async def entries():
+                yield Entry("usage/", 0)
+                yield Entry("usage/a.bin", 10)
+                yield Entry("usage/sub/b.bin", 32)
+
+            return entries()
+
+        async def stat(self, path):
+            raise AssertionError("du should not stat listed entries")
+
+    memory_fs.async_fs = SizedOperator()
+
+    assert await memory_fs._du("usage") == 42
+    assert await memory_fs._du("usage", total=False) == {
+        "usage/a.bin": 10,
+        "usage/sub/b.bin": 32,
+    }
+
+
+def test_du_falls_back_without_listed_sizes(memory_fs):
+    memory_fs.pipe_file("usage/a.bin", b"x" * 10)
+    memory_fs.pipe_file("usage/sub/b.bin", b"x" * 32)
+    assert memory_fs.du("usage") == 42
+
+
+def test_concat_assembles_parts_in_order(memory_fs):
+    for i in range(3):
+        memory_fs.pipe_file(f"parts/{i}.log", f"line {i}\n".encode())
+
+    size = memory_fs.concat("compacted.log", [f"parts/{i}.log" for i in range(3)])
+
+    assert memory_fs.cat_file("compacted.log") == b"line 0\nline 1\nline 2\n"
+    assert size == memory_fs.info("compacted.log")["size"]
+
+    memory_fs.concat("parts/0.log", ["parts/0.log", "parts/2.log"])
+    assert memory_fs.cat_file("parts/0.log") == b"line 0\nline 2\n"
+
+    with pytest.raises(ValueError):
+        memory_fs.concat("empty.log", [])
+
+
+def test_concat_into_one_of_its_sources_on_local_fs(tmp_path):
+    from opendalfs import OpendalFileSystem
+
+    fs = OpendalFileSystem(scheme="fs", root=str(tmp_path), skip_instance_cache=True)
+    fs.pipe_file("a.log", b"first\n")
+    fs.pipe_file("b.log", b"second\n")
+
+    fs.concat("b.log", ["a.log", "b.log"])
+
+    assert fs.cat_file("b.log") == b"first\nsecond\n"
+    assert sorted(os.listdir(tmp_path)) == ["a.log", "b.log"]
+
+
+def test_getitems_and_setitems_batch_keys(memory_fs):
+    memory_fs.setitems({f"kv/{i}": str(i).encode() for i in range(20)}, max_concurrency=4)
+
+    keys = [f"kv/{i}" for i in range(20)] + ["kv/missing"]
+    values = memory_fs.getitems(keys, max_concurrency=4)
+    assert values == {f"kv/{i}": str(i).encode() for i in range(20)}
+
+    returned = memory_fs.getitems(["kv/0", "kv/missing"], on_error="return")
+    assert returned["kv/0"] == b"0"
+    assert isinstance(returned["kv/missing"], FileNotFoundError)
+    with pytest.raises(FileNotFoundError):
+        memory_fs.getitems(["kv/0", "kv/missing"], on_error="raise")
+    with pytest.raises(ValueError):
+        memory_fs.getitems(["kv/0"], on_error="ignore")
+
+
+@pytest.mark.parametrize(
+    "retry", [True, {"max_times": 3, "min_delay": 0.01, "max_delay": 0.1, "jitter": True}]
+)
+def test_retry_layer_wraps_operators(retry):
+    from opendalfs import OpendalFileSystem
+
+    for blocking in (False, True):
+        fs = OpendalFileSystem(
+            scheme="memory", retry=retry, blocking=blocking, skip_instance_cache=True
+        )
+        fs.pipe_file("a.txt", b"hello")
+        assert fs.cat_file("a.txt") == b"hello"
+        assert fs.storage_options["retry"] == retry
+
+
+def test_max_concurrency_limits_backend_requests():
+    from concurrent.futures import ThreadPoolExecutor
+
+    from opendalfs import OpendalFileSystem
+
+    for blocking in (False, True):
+        fs = OpendalFileSystem(
+            scheme="memory", max_concurrency=2, blocking=blocking, skip_instance_cache=True
+        )
+        fs.pipe({f"part/{i}": bytes([i]) * 10 for i in range(16)})
+        with ThreadPoolExecutor(8) as pool:
+            values = list(pool.map(fs.cat_file, [f"part/{i}" for i in range(16)]))
+        assert values == [bytes([i]) * 10 for i in range(16)]
+
+
+def test_runtime_stats_report_calls_in_flight():
+    import asyncio
+
+    from opendalfs import OpendalFileSystem, RuntimeStats
+
+    fs = OpendalFileSystem(scheme="memory", priority_slots=1, skip_instance_cache=True)
+    idle = fs.runtime_stats()
+    assert isinstance(idle, RuntimeStats)
+    assert (idle.inflight, idle.slots_in_use, idle.queued) == (0, 0, 0)
+
+    release = asyncio.Event()
+
+    async def hold():
+        await release.wait()
+
+    async def run():
+        first = asyncio.create_task(fs._call(hold))
+        second = asyncio.create_task(fs._call(hold))
+        await asyncio.sleep(0.05)
+        stats = fs.runtime_stats()
+        release.set()
+        await asyncio.gather(first, second)
+        return stats
+
+    busy = asyncio.run_coroutine_threadsafe(run(), fs.loop).result(5)
+    assert busy.slots_in_use == 1
+    assert busy.queued == 1
+    assert busy.loop_tasks >= 3
+    assert fs.runtime_stats().slots_in_use == 0
+
+
+def test_logging_records_backend_calls(caplog):
+    import logging
+
+    from opendalfs import OpendalFileSystem
+
+    fs = OpendalFileSystem(
+        scheme="memory", logging=True, log_level="info", skip_instance_cache=True
+    )
+    with caplog.at_level(logging.INFO, logger="opendalfs"):
+        fs.pipe_file("logged.txt", b"data")
+        with pytest.raises(FileNotFoundError):
+            fs.cat_file("missing.txt")
+
+    messages = [r.getMessage() for r in caplog.records if r.levelno == logging.INFO]
+    assert any(m.startswith("write logged.txt took") for m in messages)
+    assert any(m.startswith("read missing.txt failed after") for m in messages)
+
+    with pytest.raises(ValueError):
+        OpendalFileSystem(scheme="memory", log_level="chatty", skip_instance_cache=True)
+
+
+@pytest.mark.asyncio
+async def test_reads_fall_back_to_replicas(memory_fs):
+    from opendal.exceptions import Unexpected
+
+    class Region:
+        def __init__(self, name, up=True):
+            self.name = name
+            self.up = up
+            self.reads = 0
+
+        async def read(self, path, **kwargs):
+            self.reads += 1
+            if not self.up:
+                raise Unexpected(f"{self.name} unreachable")
+            return self.name.encode()
+
+    primary, secondary = Region("primary", up=False), Region("secondary")
+    memory_fs.async_fs = primary
+    memory_fs._replicas = [secondary]
+
+    assert await memory_fs._cat_file("a.txt") == b"secondary"
+    # The failed primary is skipped until its cooldown ends.
+    assert await memory_fs._cat_file("a.txt") == b"secondary"
+    assert (primary.reads, secondary.reads) == (1, 2)
+
+    primary.up = True
+    memory_fs._replica_down_until.clear()
+    assert await memory_fs._cat_file("a.txt") == b"primary"
+
+    primary.up = secondary.up = False
+    with pytest.raises(Unexpected):
+        await memory_fs._cat_file("a.txt")
+
+
+def test_replicas_are_built_from_overridden_options():
+    from opendalfs import OpendalFileSystem
+
+    fs = OpendalFileSystem(
+        scheme="memory",
+        root="/primary",
+        replicas=[{"root": "/secondary"}],
+        skip_instance_cache=True,
+    )
+    assert len(fs._replicas) == 1
+    fs.pipe_file("a.txt", b"data")
+    assert fs.cat_file("a.txt") == b"data"
+
+
+def test_stats_count_requests_bytes_and_errors(memory_fs):
+    memory_fs.pipe_file("a.bin", b"x" * 100)
+    assert memory_fs.cat_file("a.bin") == b"x" * 100
+    with pytest.raises(FileNotFoundError):
+        memory_fs.cat_file("missing.bin")
+    memory_fs.multipart_threshold = 0
+    with memory_fs.open("b.bin", "wb") as f:
+        f.write(b"y" * 10)
+
+    stats = memory_fs.stats()
+    assert stats["write"].requests == 1
+    assert stats["write"].bytes == 100
+    assert stats["read"].requests == 2
+    assert stats["read"].errors == 1
+    assert stats["read"].bytes == 100
+    assert stats["stream_write"].bytes == 10
+
+    memory_fs.stats(reset=True)
+    assert memory_fs.stats() == {}
+
+
+def test_success_markers(memory_fs):
+    import threading
+
+    memory_fs.pipe_file("out/part-0.parquet", b"data")
+    assert not memory_fs.is_complete("out")
+    with pytest.raises(TimeoutError):
+        memory_fs.wait_for_marker("out", timeout=0.2, interval=0.05)
+
+    timer = threading.Timer(0.2, memory_fs.mark_success, args=("out/",))
+    timer.start()
+    info = memory_fs.wait_for_marker("out", timeout=5, interval=0.05)
+    timer.join()
+
+    assert info["name"] == "out/_SUCCESS"
+    assert memory_fs.is_complete("out/")
+    assert memory_fs.cat_file("out/_SUCCESS") == b""
+
+    memory_fs.mark_success("out", marker="_DONE", content=b'{"rows": 1}')
+    assert memory_fs.is_complete("out", marker="_DONE")
+
+
+@pytest.mark.asyncio
+@pytest.mark.parametrize("user_metadata", [True, False])
+async def test_idempotent_writes_skip_landed_retries(memory_fs, user_metadata):
+    import hashlib
+    from types import SimpleNamespace
+
+    from opendal.exceptions import Unexpected
+
+    objects = {}
+    writes = []
+
+    class Capability:
+        write_with_user_metadata = user_metadata
+
+    class AmbiguousOperator:
+        """Fails the first write, after storing it unless the key is taken"""
+
+        def capability(self):
+            return Capability()
+
+        async def stat(self, path):
+            data, metadata = objects[path]
+            return SimpleNamespace(
+                user_metadata=metadata,
+                etag=f'"{hashlib.md5(data).hexdigest()}"',
+                content_length=len(data),
+            )
+
+        async def write(self, path, data, user_metadata=None, **kwargs):
+            writes.append(path)
+            if len(writes) == 1 and path in objects:
+                raise Unexpected("connection refused")
+            objects[path] = (data, user_metadata)
+            if len(writes) == 1:
+                raise Unexpected("connection reset")
+
+    memory_fs.async_fs = AmbiguousOperator()
+    memory_fs.idempotent_writes = True
+
+    await memory_fs._pipe_file("a.bin", b"data", retries=1)
+    assert writes == ["a.bin"]
+    assert (objects["a.bin"][1] is not None) == user_metadata
+
+    # An attempt that failed before landing leaves other content to replace.
+    writes.clear()
+    objects["b.bin"] = (b"old", None)
+    await memory_fs._pipe_file("b.bin", b"new", retries=1)
+    assert writes == ["b.bin", "b.bin"]
+    assert objects["b.bin"][0] == b"new"
+
+
+def test_isdir_and_isfile_take_one_stat(memory_fs):
+    memory_fs.pipe_file("a/b.txt", b"data")
+    memory_fs.inline_threshold = 1024
+    memory_fs.stats(reset=True)
+
+    assert memory_fs.isdir("a")
+    assert memory_fs.isdir("a/")
+    assert not memory_fs.isdir("a/b.txt")
+    assert memory_fs.isfile("a/b.txt")
+    assert not memory_fs.isfile("a")
+    assert not memory_fs.isfile("a/")
+    assert not memory_fs.isdir("missing")
+    assert not memory_fs.isfile("missing")
+
+    # One stat each, and no read from info() inlining small objects.
+    assert set(memory_fs.stats()) == {"stat"}
+    assert memory_fs.stats()["stat"].requests == 7
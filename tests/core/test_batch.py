@@ -0,0 +1,100 @@
+import asyncio
+
+import pytest
+
+from opendalfs.batch import run_batch
+
+
+async def _value(value, delay=0.0):
+    await asyncio.sleep(delay)
+    if isinstance(value, Exception):
+        raise value
+    return value
+
+
+@pytest.mark.asyncio
+async def test_run_batch_policies():
+    def items():
+        return [_value(1), _value(ValueError("boom")), _value(3)]
+
+    with pytest.raises(ValueError):
+        await run_batch(items(), on_error="raise")
+
+    returned = await run_batch(items(), on_error="return")
+    assert returned[0] == 1
+    assert isinstance(returned[1], ValueError)
+    assert returned[2] == 3
+
+    assert await run_batch(items(), on_error="ignore") == [1, 3]
+
+    with pytest.raises(ValueError):
+        await run_batch([], on_error="skip")
+
+
+@pytest.mark.asyncio
+async def test_run_batch_raise_cancels_siblings():
+    finished = []
+
+    async def slow():
+        await asyncio.sleep(10)
+        finished.append("slow")
+
+    with pytest.raises(RuntimeError):
+        await run_batch([slow(), _value(RuntimeError("fail"))], on_error="raise")
+    assert finished == []
+
+
+@pytest.mark.asyncio
+async def test_run_batch_limits_concurrency():
+    running = 0
+    peak = 0
+
+    async def tracked(i):
+        nonlocal running, peak
+        running += 1
+        peak = max(peak, running)
+        await asyncio.sleep(0.01)
+        running -= 1
+        return i
+
+    assert await run_batch([tracked(i) for i in range(8)], limit=2) == list(range(8))
+    assert peak == 2
+
+
+def test_retry_budget_limits():
+    from opendalfs import RetryBudget
+
+    budget = RetryBudget(max_retries=2)
+    assert budget.spend(0.1) and budget.spend(0.1)
+    assert not budget.spend(0.1)
+    assert budget.retries == 2
+
+    latency = RetryBudget(max_added_latency=0.5)
+    assert latency.spend(0.4)
+    assert not latency.spend(0.2)
+    assert latency.added_latency == pytest.approx(0.4)
+
+
+@pytest.mark.asyncio
+async def test_retry_budget_is_shared_by_a_batch(memory_fs):
+    from opendal.exceptions import Unexpected
+
+    from opendalfs import RetryBudget
+
+    class AlwaysFailing:
+        calls = 0
+
+        async def read(self, path, **kwargs):
+            AlwaysFailing.calls += 1
+            raise Unexpected("connection reset")
+
+    memory_fs.async_fs = AlwaysFailing()
+    budget = RetryBudget(max_retries=3)
+
+    results = await memory_fs._cat_ranges(
+        [f"key-{i}" for i in range(4)], None, None, retries=5, retry_budget=budget
+    )
+
+    assert all(isinstance(result, Unexpected) for result in results)
+    assert budget.retries == 3
+    assert AlwaysFailing.calls == 4 + 3
@@ -0,0 +1,55 @@
+import asyncio
+
+import pytest
+
+from opendalfs import set_memory_budget
+from opendalfs.budget import MemoryBudget
+
+
+@pytest.fixture
+def budget():
+    yield set_memory_budget(16)
+    set_memory_budget(None)
+
+
+@pytest.mark.asyncio
+async def test_reservations_wait_for_room():
+    budget = MemoryBudget(10)
+    order = []
+
+    async def hold(name, nbytes, delay):
+        async with budget.reserve(nbytes):
+            order.append(f"{name}+")
+            await asyncio.sleep(delay)
+            order.append(f"{name}-")
+
+    await asyncio.gather(hold("a", 6, 0.05), hold("b", 6, 0))
+
+    assert order == ["a+", "a-", "b+", "b-"]
+    assert budget.used == 0
+
+
+@pytest.mark.asyncio
+async def test_oversized_reservation_runs_alone():
+    budget = MemoryBudget(10)
+    async with budget.reserve(100):
+        assert budget.used == 10
+    assert budget.used == 0
+
+
+def test_blocking_reservation():
+    budget = MemoryBudget(10)
+    with budget.reserve_blocking(4):
+        assert budget.used == 4
+    assert budget.used == 0
+
+
+def test_concurrent_cat_within_budget(memory_fs, budget):
+    paths = [f"budget/{i}.bin" for i in range(20)]
+    for path in paths:
+        memory_fs.pipe_file(path, b"x" * 8)
+
+    assert memory_fs.cat(paths) == {path: b"x" * 8 for path in paths}
+    with memory_fs.open(paths[0], "rb") as f:
+        assert f.read() == b"x" * 8
+    assert budget.used == 0
@@ -0,0 +1,132 @@
+import os
+from types import SimpleNamespace
+
+from opendalfs import OpendalFileSystem
+from opendalfs.cache import DiskCache
+
+
+def test_disk_cache_roundtrip(tmp_path):
+    cache = DiskCache(tmp_path / "cache")
+    assert cache.get("memory", "a.txt", '"v1"') is None
+
+    cache.put("memory", "a.txt", '"v1"', b"hello")
+    assert cache.get("memory", "a.txt", '"v1"') == b"hello"
+    assert cache.get("memory", "a.txt", '"v2"') is None
+    assert cache.get("other", "a.txt", '"v1"') is None
+
+
+def test_disk_cache_evicts_least_recently_used(tmp_path):
+    cache = DiskCache(tmp_path, max_size=10)
+    cache.put("memory", "a", "1", b"aaaa")
+    cache.put("memory", "b", "1", b"bbbb")
+    os.utime(cache._entry_path("memory", "a", "1"), (1, 1))
+    os.utime(cache._entry_path("memory", "b", "1"), (2, 2))
+
+    assert cache.get("memory", "a", "1") == b"aaaa"
+    cache.put("memory", "c", "1", b"cccc")
+    assert cache.get("memory", "b", "1") is None
+    assert cache.get("memory", "a", "1") == b"aaaa"
+    assert cache.get("memory", "c", "1") == b"cccc"
+
+    cache.put("memory", "big", "1", b"x" * 11)
+    assert cache.get("memory", "big", "1") is None
+
+
+def _with_etag(fs, monkeypatch, etag):
+    original = fs.async_fs.stat
+
+    async def stat_with_etag(path, **kwargs):
+        metadata = await original(path, **kwargs)
+        return SimpleNamespace(
+            etag=etag, content_length=metadata.content_length, mode=metadata.mode
+        )
+
+    monkeypatch.setattr(fs.async_fs, "stat", stat_with_etag)
+
+
+def test_cat_file_uses_disk_cache(tmp_path, monkeypatch):
+    fs = OpendalFileSystem(
+        scheme="memory",
+        asynchronous=False,
+        skip_instance_cache=True,
+        cache_dir=str(tmp_path),
+    )
+    fs.pipe_file("model.bin", b"0123456789")
+    _with_etag(fs, monkeypatch, '"v1"')
+
+    assert fs.cat_file("model.bin") == b"0123456789"
+
+    # Same ETag: served from disk even though the backend content changed.
+    fs.operator.write("model.bin", b"changed")
+    assert fs.cat_file("model.bin") == b"0123456789"
+    # Ranged reads go to the backend rather than fetching the whole object.
+    assert fs.cat_file("model.bin", start=-3) == b"ged"
+
+    _with_etag(fs, monkeypatch, '"v2"')
+    assert fs.cat_file("model.bin") == b"changed"
+
+
+def test_cat_file_without_etag_bypasses_disk_cache(tmp_path, monkeypatch):
+    fs = OpendalFileSystem(
+        scheme="memory",
+        asynchronous=False,
+        skip_instance_cache=True,
+        cache_dir=str(tmp_path),
+    )
+    fs.pipe_file("plain.txt", b"abc")
+    _with_etag(fs, monkeypatch, None)
+
+    assert fs.cat_file("plain.txt") == b"abc"
+    assert list(tmp_path.iterdir()) == []
+
+
+def test_small_objects_are_inlined_with_metadata():
+    fs = OpendalFileSystem(
+        scheme="memory",
+        skip_instance_cache=True,
+        inline_threshold=16,
+        log_requests=True,
+    )
+    fs.pipe_file("conf/small.json", b'{"a": 1}')
+    fs.pipe_file("conf/large.bin", b"x" * 64)
+
+    fs.ls("conf", detail=True)
+    inlined_reads = len(fs.request_log(clear=True))
+    assert inlined_reads == 1
+
+    assert fs.cat_file("conf/small.json") == b'{"a": 1}'
+    assert fs.cat_file("conf/small.json", start=1, end=4) == b'"a"'
+    assert fs.request_log() == []
+
+    assert fs.cat_file("conf/large.bin") == b"x" * 64
+    assert len(fs.request_log()) == 1
+
+    fs.pipe_file("conf/small.json", b"{}")
+    assert fs.cat_file("conf/small.json") == b"{}"
+
+
+def test_prefetch_serves_later_reads(memory_fs):
+    memory_fs.pipe_file("shards/0.bin", b"0" * 100)
+    memory_fs.pipe_file("shards/1.bin", b"1" * 100)
+
+    future = memory_fs.prefetch(["shards/0.bin", ("shards/1.bin", 10, 20), "shards/missing.bin"])
+    failures = future.result()
+    assert list(failures) == ["shards/missing.bin"]
+
+    ops = []
+    memory_fs._before_operation = lambda operation, path: ops.append(operation)
+    assert memory_fs.cat_file("shards/0.bin") == b"0" * 100
+    assert memory_fs.cat_file("shards/1.bin", start=10, end=20) == b"1" * 10
+    assert ops == []
+
+    # Prefetched data is handed out once.
+    assert memory_fs.cat_file("shards/0.bin") == b"0" * 100
+    assert ops == ["read"]
+
+
+def test_prefetch_can_be_cancelled(memory_fs):
+    memory_fs.pipe_file("shards/0.bin", b"0")
+    future = memory_fs.prefetch(["shards/0.bin"])
+    future.cancel()
+    memory_fs.invalidate_cache()
+    assert memory_fs._prefetched == {}
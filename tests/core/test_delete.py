@@ -0,0 +1,159 @@
+import logging
+
+import pytest
+
+
+def test_plan_and_execute_prefix_delete(memory_fs):
+    for i in range(5):
+        memory_fs.pipe_file(f"bulk/part-{i}.bin", b"x")
+    memory_fs.pipe_file("keep.txt", b"k")
+
+    plan = memory_fs.plan_delete("bulk", recursive=True)
+    assert list(plan.prefixes) == ["bulk/"]
+    assert plan.total >= 5
+    assert plan.keys == []
+
+    report = memory_fs.execute_delete(plan)
+    assert report.failed == {}
+    assert report.deleted == plan.total
+    assert report.requests == 1
+    assert report.items_per_second >= 0
+    assert not memory_fs.exists("bulk/part-0.bin")
+    assert memory_fs.exists("keep.txt")
+
+
+def test_plan_groups_individual_keys(memory_fs):
+    paths = [f"keys/{name}.txt" for name in "abc"]
+    for path in paths:
+        memory_fs.pipe_file(path, b"x")
+
+    plan = memory_fs.plan_delete(paths, batch_size=2)
+    assert plan.groups == [["keys/a.txt", "keys/b.txt"], ["keys/c.txt"]]
+
+    report = memory_fs.execute_delete(plan, max_concurrency=2)
+    assert report.deleted == 3
+    assert report.requests == 3
+    assert all(not memory_fs.exists(path) for path in paths)
+
+
+@pytest.mark.asyncio
+async def test_execute_delete_collects_failures(memory_fs):
+    from opendal.exceptions import PermissionDenied
+
+    from opendalfs.delete import DeletePlan
+
+    class PartialOperator:
+        async def delete(self, path):
+            if path == "denied.txt":
+                raise PermissionDenied("denied")
+
+    memory_fs.async_fs = PartialOperator()
+    report = await memory_fs._execute_delete(DeletePlan(keys=["ok.txt", "denied.txt"]))
+
+    assert report.deleted == 1
+    assert list(report.failed) == ["denied.txt"]
+
+
+def test_mv_tree_moves_every_key(memory_fs):
+    for name in ("a.txt", "sub/b.txt", "sub/deeper/c.txt"):
+        memory_fs.pipe_file(f"src/{name}", name.encode())
+
+    memory_fs.mkdir("src/empty")
+
+    plan = memory_fs.plan_mv_tree("src", "dst")
+    assert len(plan.files) == 3
+    assert ("src/empty/", "dst/empty/") in plan.markers
+    assert ("src/sub/b.txt", "dst/sub/b.txt") in plan.pairs
+
+    report = memory_fs.mv_tree(plan)
+    assert report.ok
+    assert sorted(report.moved) == sorted(src for src, _ in plan.pairs)
+    assert memory_fs.cat_file("dst/sub/deeper/c.txt") == b"sub/deeper/c.txt"
+    assert memory_fs.isdir("dst/empty")
+    assert memory_fs.find("src", withdirs=True) == []
+
+
+@pytest.mark.asyncio
+async def test_mv_tree_rolls_back_partial_copies(memory_fs):
+    for name in ("a.txt", "b.txt"):
+        await memory_fs._pipe_file(f"src/{name}", b"x")
+
+    original = memory_fs._cp_file
+
+    async def flaky_copy(path1, path2, **kwargs):
+        if path1.endswith("b.txt"):
+            raise OSError("copy failed")
+        await original(path1, path2, **kwargs)
+
+    memory_fs._cp_file = flaky_copy
+    report = await memory_fs._mv_tree("src", "dst")
+
+    assert not report.ok
+    assert list(report.failed) == ["src/b.txt"]
+    assert report.rolled_back == ["dst/a.txt"]
+    assert report.moved == []
+    assert await memory_fs._exists("src/a.txt")
+    assert not await memory_fs._exists("dst/a.txt")
+
+
+def test_rm_removes_directories_with_remove_all(memory_fs):
+    paths = [f"many/{i}.bin" for i in range(6)]
+    for path in paths:
+        memory_fs.pipe_file(path, b"x")
+    memory_fs.pipe_file("tree/a/b.bin", b"x")
+
+    assert memory_fs.rm(paths, batch_size=4) is None
+    assert not any(memory_fs.exists(path) for path in paths)
+
+    ops = []
+    memory_fs._before_operation = lambda operation, path: ops.append(operation)
+    memory_fs.rm("tree", recursive=True)
+    # Without a lifecycle_threshold, the tree is not listed before removal.
+    assert not {"list", "scan"} & set(ops)
+    assert "remove_all" in ops
+    assert not memory_fs.exists("tree/a/b.bin")
+
+
+@pytest.mark.asyncio
+async def test_rm_reports_partial_failures(memory_fs):
+    from opendal.exceptions import PermissionDenied
+
+    from opendalfs import PartialDeleteError
+
+    for name in ("ok.txt", "denied.txt"):
+        await memory_fs._pipe_file(name, b"x")
+    delete = memory_fs.async_fs.delete
+
+    async def partial_delete(path):
+        if path == "denied.txt":
+            raise PermissionDenied("denied")
+        await delete(path)
+
+    memory_fs.async_fs.delete = partial_delete
+
+    with pytest.raises(PartialDeleteError) as excinfo:
+        await memory_fs._rm(["ok.txt", "denied.txt"])
+    assert excinfo.value.report.deleted == 1
+    assert list(excinfo.value.report.failed) == ["denied.txt"]
+
+
+def test_large_rm_suggests_lifecycle_rule(memory_fs, caplog):
+    for i in range(5):
+        memory_fs.pipe_file(f"logs/2024/part-{i}.log", b"x")
+    memory_fs.pipe_file("single.txt", b"x")
+
+    plan = memory_fs.plan_delete("logs", recursive=True)
+    assert plan.requests == 1
+    assert plan.lifecycle_suggestion(threshold=plan.total) is None
+    (rule,) = plan.lifecycle_suggestion(threshold=2)["Rules"]
+    assert rule["Filter"] == {"Prefix": "logs/"}
+    assert rule["Status"] == "Enabled"
+
+    with caplog.at_level(logging.INFO, logger="opendalfs"):
+        memory_fs.rm("logs", recursive=True, lifecycle_threshold=2)
+    assert '"Prefix": "logs/"' in caplog.text
+
+    caplog.clear()
+    with caplog.at_level(logging.INFO, logger="opendalfs"):
+        memory_fs.rm("single.txt", lifecycle_threshold=0)
+    assert "lifecycle" not in caplog.text
@@ -0,0 +1,34 @@
+import pytest
+
+
+def test_diagnose_memory(memory_fs):
+    report = memory_fs.diagnose()
+
+    assert report.ok
+    by_name = {check.name: check for check in report.checks}
+    assert list(by_name) == ["dns", "tls", "auth", "list"]
+    assert by_name["dns"].ok is None
+    assert by_name["auth"].ok is True
+    assert by_name["list"].ok is True
+    assert report.to_dict()["ok"] is True
+
+
+@pytest.mark.asyncio
+async def test_diagnose_reports_rejected_credentials(memory_fs):
+    from opendal.exceptions import PermissionDenied
+
+    class DeniedOperator:
+        async def stat(self, path):
+            raise PermissionDenied("signature does not match")
+
+        async def list(self, path):
+            raise PermissionDenied("signature does not match")
+
+    memory_fs.async_fs = DeniedOperator()
+    report = await memory_fs._diagnose()
+
+    assert not report.ok
+    by_name = {check.name: check for check in report.checks}
+    assert by_name["auth"].ok is False
+    assert "credentials rejected" in by_name["auth"].detail
+    assert by_name["list"].ok is False
@@ -0,0 +1,72 @@
+import pytest
+from opendal import exceptions as opendal_errors
+
+from opendalfs import ERROR_TRANSLATIONS, register_error_translation
+from opendalfs.operator import HookedOperator
+
+
+class TenantNotFound(FileNotFoundError):
+    pass
+
+
+class BackendMisconfigured(Exception):
+    pass
+
+
+@pytest.fixture
+def translations():
+    saved = dict(ERROR_TRANSLATIONS)
+    yield ERROR_TRANSLATIONS
+    ERROR_TRANSLATIONS.clear()
+    ERROR_TRANSLATIONS.update(saved)
+
+
+def test_default_translations(memory_fs):
+    assert ERROR_TRANSLATIONS[opendal_errors.NotFound] is FileNotFoundError
+    with pytest.raises(FileNotFoundError):
+        memory_fs.info("missing.txt")
+
+
+def test_custom_translation_of_handled_error(memory_fs, translations):
+    register_error_translation(opendal_errors.NotFound, TenantNotFound)
+    with pytest.raises(TenantNotFound):
+        memory_fs.info("missing.txt")
+
+
+def test_custom_translation_must_keep_builtin_semantics(translations):
+    with pytest.raises(TypeError):
+        register_error_translation(opendal_errors.NotFound, BackendMisconfigured)
+
+
+@pytest.mark.asyncio
+async def test_operator_errors_are_translated(memory_fs, translations):
+    class MisconfiguredOperator:
+        async def stat(self, path):
+            raise opendal_errors.ConfigInvalid("bad endpoint")
+
+    register_error_translation(opendal_errors.ConfigInvalid, BackendMisconfigured)
+    hooked = HookedOperator(memory_fs, MisconfiguredOperator(), True)
+
+    with pytest.raises(BackendMisconfigured) as excinfo:
+        await hooked.stat("a.txt")
+    assert isinstance(excinfo.value.__cause__, opendal_errors.ConfigInvalid)
+
+
+def test_custom_translation_applies_where_errors_leave_the_filesystem(memory_fs, translations):
+    register_error_translation(opendal_errors.NotFound, TenantNotFound)
+    with pytest.raises(TenantNotFound):
+        memory_fs.cat_file("missing.txt")
+
+
+@pytest.mark.asyncio
+async def test_translation_of_errors_the_filesystem_handles(memory_fs, translations):
+    class FailingOperator:
+        async def read(self, path, **kwargs):
+            raise opendal_errors.Unexpected("bad gateway")
+
+    register_error_translation(opendal_errors.Unexpected, BackendMisconfigured)
+    memory_fs.async_fs = FailingOperator()
+
+    with pytest.raises(BackendMisconfigured) as excinfo:
+        await memory_fs._cat_file("a.txt")
+    assert isinstance(excinfo.value.__cause__, opendal_errors.Unexpected)
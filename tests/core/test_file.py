@@ -92,6 +92,20 @@ async def test_open_async_append_emulated():
     assert await fs._cat_file("append.txt") == b"helloworld"
 
 
+def test_append_without_emulation_raises_capability_error():
+    from opendalfs import CapabilityError, OpendalFileSystem
+
+    fs = OpendalFileSystem(scheme="memory", emulate_missing=False, skip_instance_cache=True)
+    fs.pipe_file("append.txt", b"hello")
+
+    with pytest.raises(CapabilityError) as excinfo:
+        with fs.open("append.txt", "ab") as f:
+            f.write(b"world")
+    assert excinfo.value.capability == "write_can_append"
+    assert "emulate_missing=True" in str(excinfo.value)
+    assert fs.cat_file("append.txt") == b"hello"
+
+
 @pytest.mark.asyncio
 async def test_cat_file_ranges_async():
     from opendalfs import OpendalFileSystem
@@ -104,3 +118,347 @@ async def test_cat_file_ranges_async():
     assert await fs._cat_file("range.txt", end=-1) == b"012345678"
     assert await fs._cat_file("range.txt", start=-4, end=-1) == b"678"
     assert await fs._cat_file("range.txt", start=5, end=5) == b""
+
+
+def test_open_reuses_cached_listing(memory_fs, monkeypatch):
+    memory_fs.pipe_file("cached/a.txt", b"hello")
+    memory_fs.ls("cached")
+
+    def fail(*args, **kwargs):
+        raise AssertionError("open should not stat a listed file")
+
+    monkeypatch.setattr(memory_fs, "info", fail)
+    monkeypatch.setattr(memory_fs, "_info", fail)
+
+    with memory_fs.open("cached/a.txt", "rb") as f:
+        assert len(f) == 5
+        f.seek(0, 2)
+        assert f.tell() == 5
+        f.seek(0)
+        assert f.read() == b"hello"
+
+
+@pytest.mark.asyncio
+async def test_open_async_keeps_details():
+    from opendalfs import OpendalFileSystem
+
+    fs = OpendalFileSystem(scheme="memory", asynchronous=True, skip_instance_cache=True)
+    await fs._pipe_file("details.txt", b"hello")
+
+    async with await fs.open_async("details.txt", "rb") as f:
+        assert len(f) == 5
+        assert f.details["size"] == 5
+        assert f.details["type"] == "file"
+
+
+def test_mmap_reads_on_local_fs(tmp_path):
+    from opendalfs import OpendalFileSystem
+
+    fs = OpendalFileSystem(
+        scheme="fs",
+        root=str(tmp_path),
+        asynchronous=False,
+        skip_instance_cache=True,
+        use_mmap=True,
+    )
+    fs.pipe_file("data.bin", b"0123456789")
+
+    view = fs.cat_file("data.bin", start=2, end=5)
+    assert isinstance(view, memoryview)
+    assert bytes(view) == b"234"
+    assert bytes(fs.cat_file("data.bin")) == b"0123456789"
+
+    with fs.open("data.bin", "rb") as f:
+        f.seek(4)
+        chunk = f.read(3)
+        assert isinstance(chunk, memoryview)
+        assert bytes(chunk) == b"456"
+
+    fs.pipe_file("empty.bin", b"")
+    assert bytes(fs.cat_file("empty.bin")) == b""
+
+
+def test_get_and_put_file(memory_fs, tmp_path):
+    memory_fs.pipe_file("remote/data.bin", b"payload")
+
+    local = tmp_path / "data.bin"
+    memory_fs.get_file("remote/data.bin", str(local))
+    assert local.read_bytes() == b"payload"
+
+    upload = tmp_path / "upload.bin"
+    upload.write_bytes(b"uploaded")
+    memory_fs.put_file(str(upload), "remote/upload.bin")
+    assert memory_fs.cat_file("remote/upload.bin") == b"uploaded"
+
+
+def test_recursive_get_and_put_run_concurrently(memory_fs, tmp_path):
+    names = ["a.bin", "sub/b.bin", "sub/deeper/c.bin"]
+    for name in names:
+        memory_fs.pipe_file(f"dataset/{name}", name.encode())
+
+    local = tmp_path / "dataset"
+    memory_fs.get("dataset/", str(local), recursive=True, max_concurrency=2)
+    for name in names:
+        assert (local / name).read_bytes() == name.encode()
+
+    memory_fs.put(str(local) + "/", "copy/", recursive=True, max_concurrency=2)
+    for name in names:
+        assert memory_fs.cat_file(f"copy/{name}") == name.encode()
+
+
+def test_get_file_streams_to_disk(memory_fs, tmp_path):
+    payload = bytes(range(256)) * 1024
+    memory_fs.pipe_file("remote/big.bin", payload)
+    memory_fs.stats(reset=True)
+
+    local = tmp_path / "big.bin"
+    memory_fs.get_file("remote/big.bin", str(local))
+    assert local.read_bytes() == payload
+
+    stats = memory_fs.stats()
+    assert "read" not in stats
+    assert stats["stream_read"].bytes == len(payload)
+
+    missing = tmp_path / "missing.bin"
+    with pytest.raises(FileNotFoundError):
+        memory_fs.get_file("remote/missing.bin", str(missing))
+    assert not missing.exists()
+
+
+def test_chained_caching_filesystems(memory_fs, tmp_path):
+    import fsspec
+
+    memory_fs.pipe_file("chained.txt", b"0123456789")
+
+    simple = fsspec.filesystem("simplecache", fs=memory_fs, cache_storage=str(tmp_path / "s"))
+    with simple.open("chained.txt", "rb") as f:
+        assert f.read() == b"0123456789"
+    assert len(list((tmp_path / "s").iterdir())) >= 1
+
+    blocks = fsspec.filesystem("blockcache", fs=memory_fs, cache_storage=str(tmp_path / "b"))
+    with blocks.open("chained.txt", "rb", block_size=4) as f:
+        f.seek(5)
+        assert f.read(3) == b"567"
+
+
+def test_check_exists_false_skips_existence_probes(memory_fs):
+    operations = []
+    memory_fs._before_operation = lambda operation, path: operations.append(operation)
+
+    with memory_fs.open("fresh/new.bin", "xb", check_exists=False) as f:
+        f.write(b"new")
+    with memory_fs.open("fresh/log.bin", "ab", check_exists=False) as f:
+        f.write(b"line")
+    memory_fs.pipe_file("fresh/tiny.bin", b"x", mode="create", check_exists=False)
+
+    assert "stat" not in operations
+    assert "exists" not in operations
+    assert memory_fs.cat_file("fresh/new.bin") == b"new"
+    assert memory_fs.cat_file("fresh/log.bin") == b"line"
+
+
+def test_exclusive_open_keeps_create_semantics_without_check(memory_fs):
+    if not memory_fs._create_options():
+        pytest.skip("backend has no conditional writes")
+    memory_fs.pipe_file("fresh/taken.bin", b"old")
+
+    with pytest.raises(FileExistsError):
+        with memory_fs.open("fresh/taken.bin", "xb", check_exists=False) as f:
+            f.write(b"new")
+    assert memory_fs.cat_file("fresh/taken.bin") == b"old"
+
+
+@pytest.mark.asyncio
+async def test_empty_exclusive_file_is_created_conditionally(memory_fs):
+    from opendal.exceptions import ConditionNotMatch
+
+    writes = []
+
+    class Capability:
+        write_with_if_not_exists = True
+
+    class TakenOperator:
+        def capability(self):
+            return Capability()
+
+        async def write(self, path, data, **options):
+            writes.append((path, data, options))
+            raise ConditionNotMatch("object exists")
+
+    memory_fs.async_fs = TakenOperator()
+    f = await memory_fs.open_async("taken.bin", "xb", check_exists=False)
+    with pytest.raises(FileExistsError):
+        await f.close()
+    assert writes == [("taken.bin", b"", {"if_not_exists": True})]
+
+
+@pytest.mark.parametrize("threshold, expected", [(2**20, "write"), (0, "open")])
+def test_multipart_threshold_picks_single_put(memory_fs, threshold, expected):
+    memory_fs.multipart_threshold = threshold
+    operations = []
+    memory_fs._before_operation = lambda operation, path: operations.append(operation)
+
+    with memory_fs.open("small.bin", "wb") as f:
+        f.write(b"tiny")
+
+    assert operations == [expected]
+    assert memory_fs.cat_file("small.bin") == b"tiny"
+
+
+@pytest.mark.asyncio
+async def test_open_file_pins_etag(memory_fs):
+    from opendal.exceptions import ConditionNotMatch
+
+    from opendalfs import FileChangedError
+
+    store = {"etag": '"v1"', "data": b"0123456789"}
+
+    class Capability:
+        read_with_if_match = True
+
+    class Mode:
+        def is_dir(self):
+            return False
+
+        def is_file(self):
+            return True
+
+    class Metadata:
+        def __init__(self):
+            self.content_length = len(store["data"])
+            self.mode = Mode()
+            self.etag = store["etag"]
+            self.last_modified = None
+
+    class CapableOperator:
+        def capability(self):
+            return Capability()
+
+    class VersionedOperator:
+        async def stat(self, path):
+            return Metadata()
+
+        async def read(self, path, offset=0, size=None, if_match=None):
+            if if_match is not None and if_match != store["etag"]:
+                raise ConditionNotMatch("etag changed")
+            return store["data"][offset : offset + size]
+
+    memory_fs.operator = CapableOperator()
+    memory_fs.async_fs = VersionedOperator()
+
+    f = await memory_fs.open_async("scan.parquet", "rb")
+    assert await f.read(4) == b"0123"
+
+    store["etag"] = '"v2"'
+    with pytest.raises(FileChangedError):
+        await f.read(4)
+
+
+def test_open_with_size_sends_no_head(memory_fs):
+    memory_fs.pipe_file("sized.bin", b"0123456789")
+    operations = []
+    memory_fs._before_operation = lambda operation, path: operations.append(operation)
+
+    with memory_fs.open("sized.bin", "rb", size=10) as f:
+        assert f.read(4) == b"0123"
+    assert "stat" not in operations
+
+
+def test_fsync_on_local_fs_makes_bytes_visible(tmp_path):
+    from opendalfs import OpendalFileSystem
+
+    fs = OpendalFileSystem(scheme="fs", root=str(tmp_path), skip_instance_cache=True)
+    assert fs.durable_on_flush
+
+    with fs.open("checkpoint.bin", "wb") as f:
+        f.write(b"step-1")
+        f.fsync()
+        assert (tmp_path / "checkpoint.bin").read_bytes() == b"step-1"
+        f.write(b",step-2")
+    assert (tmp_path / "checkpoint.bin").read_bytes() == b"step-1,step-2"
+
+
+@pytest.mark.asyncio
+async def test_async_fsync_on_local_fs_makes_bytes_visible(tmp_path):
+    from opendalfs import OpendalFileSystem
+
+    fs = OpendalFileSystem(
+        scheme="fs", root=str(tmp_path), asynchronous=True, skip_instance_cache=True
+    )
+    f = await fs.open_async("checkpoint.bin", "wb")
+    await f.write(b"step-1")
+    await f.fsync()
+    assert (tmp_path / "checkpoint.bin").read_bytes() == b"step-1"
+    await f.write(b",step-2")
+    await f.close()
+    assert (tmp_path / "checkpoint.bin").read_bytes() == b"step-1,step-2"
+
+
+def test_fsync_is_refused_by_object_stores(memory_fs):
+    import io
+
+    assert not memory_fs.durable_on_flush
+    with memory_fs.open("checkpoint.bin", "wb") as f:
+        f.write(b"data")
+        with pytest.raises(io.UnsupportedOperation):
+            f.fsync()
+    assert memory_fs.cat_file("checkpoint.bin") == b"data"
+
+
+def test_close_reports_size_and_checksum(memory_fs):
+    import hashlib
+
+    f = memory_fs.open("ingest/a.bin", "wb", checksum="sha256")
+    f.write(b"hello ")
+    f.write(memoryview(b"world"))
+    result = f.close()
+
+    assert result.path == "ingest/a.bin"
+    assert result.size == 11
+    assert result.checksum == hashlib.sha256(b"hello world").hexdigest()
+    assert f.close() is result
+
+    with memory_fs.open("ingest/b.bin", "wb") as plain:
+        plain.write(b"x")
+    assert plain.result is None
+
+
+@pytest.mark.asyncio
+async def test_async_close_reports_checksum():
+    import hashlib
+
+    from opendalfs import OpendalFileSystem
+
+    fs = OpendalFileSystem(scheme="memory", asynchronous=True, skip_instance_cache=True)
+
+    async with await fs.open_async("ingest.bin", "wb", checksum="md5") as f:
+        await f.write(b"abc")
+
+    assert f.result.size == 3
+    assert f.result.checksum == hashlib.md5(b"abc").hexdigest()
+
+
+def test_sequential_reads_are_fetched_ahead():
+    from opendalfs import OpendalFileSystem
+
+    fs = OpendalFileSystem(
+        scheme="memory", readahead=64, log_requests=True, skip_instance_cache=True
+    )
+    data = bytes(range(256))
+    fs.pipe_file("seq.bin", data)
+
+    with fs.open("seq.bin", "rb", block_size=16, cache_type="none") as f:
+        assert b"".join(f.read(16) for _ in range(16)) == data
+
+    # One read for the first block, then one background fetch per 64 bytes.
+    assert [(r.offset, r.size) for r in fs.request_log()] == [
+        (0, 16),
+        (16, 64),
+        (80, 64),
+        (144, 64),
+        (208, 48),
+    ]
+
+    with fs.open("seq.bin", "rb", cache_type="none", readahead=None) as f:
+        f.seek(100)
+        assert f.read(8) == data[100:108]
@@ -0,0 +1,40 @@
+"""Listings and summaries exported as DataFrames."""
+
+import pytest
+
+from opendalfs import to_arrow, to_pandas, to_polars
+
+pa = pytest.importorskip("pyarrow")
+
+
+@pytest.fixture
+def listing(memory_fs):
+    memory_fs.pipe_file("frames/a.csv", b"1,2")
+    memory_fs.pipe_file("frames/b.csv", b"3,4,5")
+    return memory_fs.ls("frames", detail=True)
+
+
+def test_to_arrow_listing(listing, memory_fs):
+    table = to_arrow(listing)
+    assert table.column_names[:3] == ["name", "size", "type"]
+    assert table.column("size").to_pylist() == [3, 5]
+
+    names = to_arrow(memory_fs.ls("frames", detail=False))
+    assert names.column_names == ["name"]
+
+
+def test_to_pandas_and_summary(listing, memory_fs):
+    pytest.importorskip("pandas")
+
+    frame = to_pandas(listing)
+    assert frame["size"].sum() == 8
+
+    histogram = to_pandas(memory_fs.summary("frames"))
+    assert list(histogram.columns) == ["size_below", "files"]
+    assert histogram["files"].sum() == 2
+
+
+def test_to_polars(listing):
+    pytest.importorskip("polars")
+
+    assert to_polars(listing)["size"].sum() == 8
@@ -0,0 +1,51 @@
+from opendalfs.globbing import expand_braces
+
+
+def test_expand_braces():
+    assert expand_braces("a/*.txt") == ["a/*.txt"]
+    assert expand_braces("y={2023,2024}/m=*") == ["y=2023/m=*", "y=2024/m=*"]
+    assert expand_braces("{a,b{c,d}}/x") == ["a/x", "bc/x", "bd/x"]
+    assert expand_braces("{a}/{b,c}") == ["{a}/b", "{a}/c"]
+    assert expand_braces("{a,b") == ["{a,b"]
+
+
+def test_glob_brace_and_class(memory_fs):
+    for year in ("2022", "2023", "2024"):
+        for month in ("1", "2"):
+            memory_fs.pipe_file(f"data/year={year}/month={month}/part-0.parquet", b"x")
+
+    assert memory_fs.glob("data/year={2023,2024}/month=*/part-*.parquet") == [
+        "data/year=2023/month=1/part-0.parquet",
+        "data/year=2023/month=2/part-0.parquet",
+        "data/year=2024/month=1/part-0.parquet",
+        "data/year=2024/month=2/part-0.parquet",
+    ]
+    assert memory_fs.glob("data/year=202[3-4]/month=1/*.parquet") == [
+        "data/year=2023/month=1/part-0.parquet",
+        "data/year=2024/month=1/part-0.parquet",
+    ]
+    assert memory_fs.glob("data/**/month={2,3}/*.parquet") == [
+        "data/year=2022/month=2/part-0.parquet",
+        "data/year=2023/month=2/part-0.parquet",
+        "data/year=2024/month=2/part-0.parquet",
+    ]
+
+    detailed = memory_fs.glob("data/year={2022,2022}/month=1/*", detail=True)
+    assert list(detailed) == ["data/year=2022/month=1/part-0.parquet"]
+
+
+def test_single_segment_glob_lists_one_directory(memory_fs):
+    for name in ("2024-01.gz", "2024-02.gz", "2023-12.gz", "2024-03.txt"):
+        memory_fs.pipe_file(f"logs/{name}", b"x")
+    memory_fs.pipe_file("logs/archive/2024-01.gz", b"x")
+    memory_fs.invalidate_cache()
+
+    calls = []
+    memory_fs._before_operation = lambda operation, path: calls.append((operation, path))
+
+    assert memory_fs.glob("logs/2024-*.gz") == ["logs/2024-01.gz", "logs/2024-02.gz"]
+    assert [call for call in calls if call[0] == "list"] == [("list", "logs/")]
+
+    detail = memory_fs.glob("logs/*", detail=True)
+    assert detail["logs/archive"]["type"] == "directory"
+    assert memory_fs.glob("missing/*.gz") == []
@@ -0,0 +1,26 @@
+from opendalfs import OpendalFileSystem, health
+
+
+def test_health_reports_each_filesystem(memory_fs):
+    from opendal.exceptions import PermissionDenied
+
+    class DeniedOperator:
+        def capability(self):
+            return memory_fs.operator.capability()
+
+        async def stat(self, path):
+            raise PermissionDenied("signature does not match")
+
+    denied = OpendalFileSystem(scheme="memory", skip_instance_cache=True)
+    denied.async_fs = DeniedOperator()
+
+    ok, failing = health([memory_fs, denied], timeout=5)
+
+    assert ok.reachable
+    assert ok.scheme == "memory"
+    assert set(ok.latency) == {"stat", "list"}
+    assert "read" in ok.capabilities and "write" in ok.capabilities
+
+    assert not failing.reachable
+    assert failing.error.startswith("stat: PermissionError: credentials rejected")
+    assert failing.to_dict()["latency"] == {}
@@ -0,0 +1,40 @@
+from opendalfs import OpendalFileSystem
+
+
+class TenantFileSystem(OpendalFileSystem):
+    prefix = "tenant-a/"
+
+    def _to_backend_path(self, path):
+        return self.prefix + path.lstrip("/")
+
+    def _from_backend_path(self, path):
+        return path.removeprefix(self.prefix)
+
+    def _before_operation(self, operation, path):
+        self.seen.append((operation, path))
+
+
+def test_path_hooks_confine_to_prefix():
+    fs = TenantFileSystem(scheme="memory", asynchronous=False, skip_instance_cache=True)
+    fs.seen = []
+
+    fs.pipe_file("report.csv", b"a,b")
+    fs.pipe_file("nested/item.txt", b"x")
+
+    assert fs.cat_file("report.csv") == b"a,b"
+    assert fs.operator.inner.read("tenant-a/report.csv") == b"a,b"
+    assert set(fs.ls("", detail=False)) == {"report.csv", "nested/"}
+    assert fs.ls("nested", detail=False) == ["nested/item.txt"]
+    assert ("write", "report.csv") in fs.seen
+
+
+def test_after_operation_can_replace_results():
+    class UppercaseFileSystem(OpendalFileSystem):
+        def _after_operation(self, operation, path, result):
+            if operation == "read":
+                return result.upper()
+            return result
+
+    fs = UppercaseFileSystem(scheme="memory", asynchronous=False, skip_instance_cache=True)
+    fs.pipe_file("lower.txt", b"hello")
+    assert fs.cat_file("lower.txt") == b"HELLO"
@@ -0,0 +1,76 @@
+"""Object keys with special characters must round-trip unchanged."""
+
+import random
+import unicodedata
+
+import pytest
+
+from opendalfs.registry import OpendalS3FileSystem
+
+SPECIAL_KEYS = [
+    "with space.txt",
+    "hash#fragment.txt",
+    "question?mark.txt",
+    "percent%20encoded.txt",
+    "plus+sign.txt",
+    "ünïcødé/ключ/数据.bin",
+    "emoji/📁/🚀.txt",
+    "a&b=c;d.txt",
+]
+
+
+def _random_char(rng: random.Random) -> str:
+    while True:
+        char = chr(rng.randint(0x20, 0x2FFFF))
+        if char not in "/\\" and unicodedata.category(char) not in ("Cs", "Cc", "Cn"):
+            return char
+
+
+def _random_key(rng: random.Random) -> str:
+    """Up to three segments of arbitrary printable characters under prop/"""
+    parts = []
+    for _ in range(rng.randint(1, 3)):
+        segment = ""
+        while segment.strip() in ("", ".", ".."):
+            segment = "".join(_random_char(rng) for _ in range(rng.randint(1, 12)))
+        parts.append(segment)
+    return "prop/" + "/".join(parts)
+
+
+# Seeded, so a failing key shows up again on the next run.
+RANDOM_KEYS = [_random_key(random.Random(seed)) for seed in range(50)]
+
+
+def _roundtrip(fs, key: str) -> None:
+    fs.pipe_file(key, key.encode())
+    assert fs.cat_file(key) == key.encode()
+    assert fs.info(key)["name"] == key
+    parent = key.rsplit("/", 1)[0] if "/" in key else ""
+    assert key in fs.ls(parent, detail=False)
+
+
+@pytest.mark.parametrize("key", SPECIAL_KEYS)
+def test_special_character_keys_roundtrip(memory_fs, key):
+    _roundtrip(memory_fs, key)
+
+
+@pytest.mark.parametrize("key", RANDOM_KEYS, ids=range(len(RANDOM_KEYS)))
+def test_arbitrary_keys_roundtrip(memory_fs, key):
+    _roundtrip(memory_fs, key)
+
+
+@pytest.mark.parametrize("key", SPECIAL_KEYS)
+def test_urls_keep_keys_verbatim(key):
+    url = f"opendal+s3://MixedCase/{key}"
+    assert OpendalS3FileSystem._strip_protocol(url) == key
+    assert OpendalS3FileSystem._get_kwargs_from_urls(url) == {"bucket": "MixedCase"}
+
+
+def test_url_query_options_still_parsed():
+    url = "opendal+s3://bucket/data.csv?region=eu-west-1&endpoint=http://localhost:9000"
+    assert OpendalS3FileSystem._strip_protocol(url) == "data.csv"
+    assert OpendalS3FileSystem._get_kwargs_from_urls(url) == {
+        "bucket": "bucket",
+        "region": "eu-west-1",
+        "endpoint": "http://localhost:9000",
+    }
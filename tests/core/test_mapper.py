@@ -0,0 +1,49 @@
+import pytest
+
+from opendalfs.mapper import OpendalMapper
+
+
+def test_mapper_reads_and_writes_keys(memory_fs):
+    store = memory_fs.get_mapper("array")
+    assert isinstance(store, OpendalMapper)
+
+    store[".zarray"] = b"{}"
+    store.setitems({"0.0": b"a", "0.1": b"b"})
+
+    assert memory_fs.cat_file("array/0.1") == b"b"
+    assert store["0.0"] == b"a"
+    assert sorted(store) == [".zarray", "0.0", "0.1"]
+    assert len(store) == 3
+
+
+def test_mapper_getitems_error_policies(memory_fs):
+    store = memory_fs.get_mapper("array")
+    store.setitems({"0.0": b"a", "0.1": b"b"})
+
+    assert store.getitems(["0.0", "0.1"]) == {"0.0": b"a", "0.1": b"b"}
+    assert store.getitems(["0.0", "9.9"], on_error="omit") == {"0.0": b"a"}
+    returned = store.getitems(["0.0", "9.9"], on_error="return")
+    assert isinstance(returned["9.9"], KeyError)
+    with pytest.raises(KeyError):
+        store.getitems(["0.0", "9.9"])
+    with pytest.raises(KeyError):
+        store["9.9"]
+    assert store.get("9.9", b"fill") == b"fill"
+
+
+def test_mapper_membership_and_deletes(memory_fs):
+    store = memory_fs.get_mapper("array")
+    store.setitems({"0.0": b"a", "0.1": b"b", "nested/0": b"c"})
+
+    assert "0.0" in store
+    assert "nested" not in store
+    assert store.contains_many(["0.0", "0.1", "1.0"]) == {
+        "0.0": True,
+        "0.1": True,
+        "1.0": False,
+    }
+
+    store.delitems(["0.0", "0.1"])
+    assert store.contains_many(["0.0", "0.1"]) == {"0.0": False, "0.1": False}
+    del store["nested/0"]
+    assert "nested/0" not in store
@@ -0,0 +1,33 @@
+import time
+
+
+def test_mirror_to_local_tracks_changes(memory_fs, tmp_path):
+    memory_fs.pipe_file("mirror/a.txt", b"one")
+    memory_fs.pipe_file("mirror/sub/b.txt", b"two")
+
+    mirror = memory_fs.mirror_to_local("mirror", tmp_path / "out")
+    assert (tmp_path / "out" / "a.txt").read_bytes() == b"one"
+    assert (tmp_path / "out" / "sub" / "b.txt").read_bytes() == b"two"
+    assert mirror.refresh() == []
+
+    memory_fs.pipe_file("mirror/a.txt", b"changed")
+    memory_fs.rm_file("mirror/sub/b.txt")
+    assert mirror.refresh() == ["mirror/a.txt"]
+    assert (tmp_path / "out" / "a.txt").read_bytes() == b"changed"
+    assert not (tmp_path / "out" / "sub" / "b.txt").exists()
+
+
+def test_mirror_skips_keys_escaping_directory(memory_fs, tmp_path):
+    mirror = memory_fs.mirror_to_local("mirror", tmp_path / "out")
+    assert mirror.local_path("mirror/../../etc/passwd") is None
+    assert mirror.local_path("mirror/ok.txt") == str(tmp_path / "out" / "ok.txt")
+
+
+def test_mirror_watch_picks_up_new_files(memory_fs, tmp_path):
+    with memory_fs.mirror_to_local("watched", tmp_path, watch=True, interval=0.01):
+        memory_fs.pipe_file("watched/new.txt", b"fresh")
+        deadline = time.monotonic() + 5
+        while not (tmp_path / "new.txt").exists() and time.monotonic() < deadline:
+            time.sleep(0.01)
+
+    assert (tmp_path / "new.txt").read_bytes() == b"fresh"
@@ -0,0 +1,37 @@
+import pytest
+
+from opendalfs import OperatorPool
+
+
+def test_pool_reuses_filesystems_per_key():
+    pool = OperatorPool(max_size=4)
+    first = pool.get("memory", root="/a")
+    assert pool.get("memory", root="/a") is first
+    assert pool.get("memory", root="/b") is not first
+    assert len(pool) == 2
+
+
+def test_pool_keys_options_holding_dicts():
+    pool = OperatorPool(max_size=4)
+    first = pool.get("memory", root="/a", retry={"max_times": 3})
+    assert pool.get("memory", root="/a", retry={"max_times": 3}) is first
+    assert pool.get("memory", root="/a", retry={"max_times": 5}) is not first
+
+
+def test_pool_evicts_least_recently_used():
+    pool = OperatorPool(max_size=2)
+    a = pool.get("memory", root="/a")
+    pool.get("memory", root="/b")
+    assert pool.get("memory", root="/a") is a  # refreshes /a
+
+    pool.get("memory", root="/c")  # evicts /b
+    assert len(pool) == 2
+    assert pool.get("memory", root="/a") is a
+
+    pool.clear()
+    assert len(pool) == 0
+
+
+def test_pool_rejects_empty_size():
+    with pytest.raises(ValueError):
+        OperatorPool(max_size=0)
@@ -1,6 +1,12 @@
+import pytest
+
 from opendalfs.registry import (
+    OpendalAlluxioFileSystem,
     OpendalAzBlobFileSystem,
+    OpendalCephRGWFileSystem,
     OpendalGCSFileSystem,
+    OpendalLocalFileSystem,
+    OpendalOSSFileSystem,
     OpendalS3FileSystem,
     register_opendal_protocols,
     register_opendal_service,
@@ -11,11 +17,23 @@ def test_register_default_protocols():
     from fsspec.registry import get_filesystem_class
 
     registered = register_opendal_protocols()
-    assert registered == ["opendal+azblob", "opendal+gcs", "opendal+s3"]
+    assert registered == [
+        "opendal+alluxio",
+        "opendal+azblob",
+        "opendal+fs",
+        "opendal+gcs",
+        "opendal+oss",
+        "opendal+rgw",
+        "opendal+s3",
+    ]
 
     assert get_filesystem_class("opendal+s3") is OpendalS3FileSystem
     assert get_filesystem_class("opendal+gcs") is OpendalGCSFileSystem
     assert get_filesystem_class("opendal+azblob") is OpendalAzBlobFileSystem
+    assert get_filesystem_class("opendal+rgw") is OpendalCephRGWFileSystem
+    assert get_filesystem_class("opendal+fs") is OpendalLocalFileSystem
+    assert get_filesystem_class("opendal+oss") is OpendalOSSFileSystem
+    assert get_filesystem_class("opendal+alluxio") is OpendalAlluxioFileSystem
 
 
 def test_strip_protocol_and_kwargs():
@@ -39,9 +57,174 @@ def test_strip_protocol_and_kwargs():
 def test_dynamic_service_registration():
     from fsspec.registry import get_filesystem_class
 
-    protocol = register_opendal_service("oss")
-    assert protocol == "opendal+oss"
+    protocol = register_opendal_service("cos")
+    assert protocol == "opendal+cos"
+
+    cls = get_filesystem_class("opendal+cos")
+    assert cls.protocol == "opendal+cos"
+    assert cls.service == "cos"
+
+
+def test_concurrent_registration_yields_one_class():
+    from concurrent.futures import ThreadPoolExecutor
+
+    from fsspec.registry import get_filesystem_class
+
+    with ThreadPoolExecutor(max_workers=8) as pool:
+        protocols = list(pool.map(lambda _: register_opendal_service("webdav"), range(32)))
+
+    assert set(protocols) == {"opendal+webdav"}
+    assert get_filesystem_class("opendal+webdav").service == "webdav"
+
+
+def test_ceph_rgw_preset():
+    with pytest.raises(ValueError):
+        OpendalCephRGWFileSystem(bucket="data", skip_instance_cache=True)
+
+    fs = OpendalCephRGWFileSystem(
+        bucket="data",
+        endpoint="http://rgw.local:7480",
+        access_key_id="key",
+        secret_access_key="secret",
+        skip_instance_cache=True,
+    )
+    assert fs.scheme == "s3"
+    assert fs.storage_options["endpoint"] == "http://rgw.local:7480"
+
+    swift = OpendalCephRGWFileSystem(
+        bucket="data",
+        endpoint="http://rgw.local:7480/swift/v1",
+        keystone_token="token",
+        skip_instance_cache=True,
+    )
+    assert swift.scheme == "swift"
+    assert swift.with_credentials(keystone_token="rotated").scheme == "swift"
+    # Rebuilt from the options the swift operator was given.
+    rebuilt = OpendalCephRGWFileSystem(
+        scheme="swift",
+        container="data",
+        endpoint="http://rgw.local:7480/swift/v1",
+        token="token",
+        skip_instance_cache=True,
+    )
+    assert rebuilt.scheme == "swift"
+    swift.reconfigure(keystone_token="rotated")
+    assert swift.scheme == "swift"
+    assert OpendalCephRGWFileSystem._get_kwargs_from_urls("opendal+rgw://data/key")[
+        "bucket"
+    ] == "data"
+
+
+def test_azblob_azurite_and_anonymous():
+    fs = OpendalAzBlobFileSystem.azurite("test", skip_instance_cache=True)
+    assert fs.scheme == "azblob"
+    assert fs.storage_options["container"] == "test"
+    assert fs.storage_options["endpoint"] == "http://127.0.0.1:10000/devstoreaccount1"
+    assert fs.storage_options["account_name"] == "devstoreaccount1"
+
+    custom = OpendalAzBlobFileSystem.azurite(
+        "test", endpoint="http://azurite:10000/devstoreaccount1", skip_instance_cache=True
+    )
+    assert custom.storage_options["endpoint"] == "http://azurite:10000/devstoreaccount1"
+
+    anonymous = OpendalAzBlobFileSystem(
+        container="public",
+        endpoint="https://account.blob.core.windows.net",
+        account_key="ignored",
+        anonymous=True,
+        skip_instance_cache=True,
+    )
+    assert anonymous.scheme == "azblob"
+
+
+def test_gcs_fake_gcs_server():
+    fs = OpendalGCSFileSystem.fake_gcs_server("test", skip_instance_cache=True)
+    assert fs.scheme == "gcs"
+    assert fs.storage_options["bucket"] == "test"
+    assert fs.storage_options["endpoint"] == "http://localhost:4443"
+    assert fs.storage_options["allow_anonymous"] == "true"
+
+    custom = OpendalGCSFileSystem.fake_gcs_server(
+        "test", endpoint="http://gcs:4443", skip_instance_cache=True
+    )
+    assert custom.storage_options["endpoint"] == "http://gcs:4443"
+
+
+def test_azblob_named_options_and_aliases():
+    from fsspec.registry import get_filesystem_class
+
+    fs = OpendalAzBlobFileSystem(
+        container="data",
+        account_name="acct",
+        sas_token="sv=2024",
+        root="/prefix",
+        skip_instance_cache=True,
+    )
+    assert fs.storage_options["container"] == "data"
+    assert fs.storage_options["endpoint"] == "https://acct.blob.core.windows.net"
+    assert fs.storage_options["root"] == "/prefix"
+
+    assert OpendalAzBlobFileSystem._strip_protocol("az://data/dir/file") == "dir/file"
+    kwargs = OpendalAzBlobFileSystem._get_kwargs_from_urls(
+        "abfs://data@acct.dfs.core.windows.net/dir/file"
+    )
+    assert kwargs == {"container": "data", "account_name": "acct"}
+
+    registered = register_opendal_protocols(["azblob"], aliases=True)
+    assert registered == ["abfs", "az", "opendal+azblob"]
+    assert get_filesystem_class("az") is OpendalAzBlobFileSystem
+    assert get_filesystem_class("abfs") is OpendalAzBlobFileSystem
+
+
+def test_local_fs_service(tmp_path):
+    import fsspec
 
-    cls = get_filesystem_class("opendal+oss")
-    assert cls.protocol == "opendal+oss"
-    assert cls.service == "oss"
+    target = tmp_path / "data.txt"
+    with fsspec.open(f"opendal+fs://{target}", "wb") as f:
+        f.write(b"local")
+
+    assert target.read_bytes() == b"local"
+    assert OpendalLocalFileSystem._strip_protocol(f"opendal+fs://{target}") == str(
+        target
+    ).lstrip("/")
+
+    fs = OpendalLocalFileSystem(root=str(tmp_path), skip_instance_cache=True)
+    assert fs.cat_file("data.txt") == b"local"
+
+
+def test_oss_named_options():
+    fs = OpendalOSSFileSystem(
+        bucket="data",
+        region="oss-cn-hangzhou",
+        access_key_id="id",
+        access_key_secret="secret",
+        skip_instance_cache=True,
+    )
+    assert fs.scheme == "oss"
+    assert fs.storage_options["bucket"] == "data"
+    assert fs.storage_options["endpoint"] == "https://oss-cn-hangzhou.aliyuncs.com"
+    assert OpendalOSSFileSystem._get_kwargs_from_urls("opendal+oss://data/key") == {
+        "bucket": "data"
+    }
+
+
+def test_alluxio_preset():
+    with pytest.raises(ValueError):
+        OpendalAlluxioFileSystem(skip_instance_cache=True)
+
+    kwargs = OpendalAlluxioFileSystem._get_kwargs_from_urls(
+        "opendal+alluxio://proxy/data/file"
+    )
+    assert kwargs == {"endpoint": "http://proxy:39999"}
+    assert OpendalAlluxioFileSystem._get_kwargs_from_urls(
+        "opendal+alluxio://proxy:8080/data"
+    ) == {"endpoint": "http://proxy:8080"}
+    assert OpendalAlluxioFileSystem._strip_protocol("opendal+alluxio://proxy/data/file") == (
+        "data/file"
+    )
+
+    fs = OpendalAlluxioFileSystem(
+        endpoint="https://gateway.local", root="/warm", skip_instance_cache=True
+    )
+    assert fs.scheme == "alluxio"
+    assert fs.storage_options["root"] == "/warm"
@@ -0,0 +1,44 @@
+"""pyarrow reads and writes through opendalfs file objects."""
+
+import pytest
+
+pa = pytest.importorskip("pyarrow")
+pq = pytest.importorskip("pyarrow.parquet")
+pafs = pytest.importorskip("pyarrow.fs")
+
+
+@pytest.fixture
+def arrow_fs(memory_fs):
+    return pafs.PyFileSystem(pafs.FSSpecHandler(memory_fs))
+
+
+def test_parquet_roundtrip(arrow_fs, memory_fs):
+    table = pa.table({"id": list(range(1000)), "name": [f"row-{i}" for i in range(1000)]})
+    pq.write_table(table, "datasets/table.parquet", filesystem=arrow_fs)
+
+    assert memory_fs.info("datasets/table.parquet")["size"] > 0
+    assert pq.read_table("datasets/table.parquet", filesystem=arrow_fs).equals(table)
+    columns = pq.read_table("datasets/table.parquet", columns=["id"], filesystem=arrow_fs)
+    assert columns.column("id").to_pylist() == list(range(1000))
+
+
+def test_random_access_input_file(arrow_fs, memory_fs):
+    memory_fs.pipe_file("blob.bin", bytes(range(256)))
+
+    with arrow_fs.open_input_file("blob.bin") as f:
+        assert f.size() == 256
+        f.seek(250)
+        assert f.read(4) == bytes([250, 251, 252, 253])
+        assert f.tell() == 254
+        assert f.read_at(2, 10) == bytes([10, 11])
+
+
+def test_output_stream(arrow_fs, memory_fs):
+    with arrow_fs.open_output_stream("out/stream.bin") as f:
+        f.write(b"part-1,")
+        f.write(b"part-2")
+    assert memory_fs.cat_file("out/stream.bin") == b"part-1,part-2"
+
+    (info,) = arrow_fs.get_file_info(["out/stream.bin"])
+    assert info.type == pafs.FileType.File
+    assert info.size == 13
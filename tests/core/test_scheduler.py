@@ -0,0 +1,58 @@
+import asyncio
+
+import pytest
+
+from opendalfs.scheduler import PriorityScheduler
+
+
+@pytest.mark.asyncio
+async def test_freed_slots_go_to_higher_priority_first():
+    scheduler = PriorityScheduler(1)
+    order = []
+    release = asyncio.Event()
+
+    async def call(name, priority):
+        async with scheduler.slot(priority):
+            order.append(name)
+            if name == "first":
+                await release.wait()
+
+    first = asyncio.create_task(call("first", "normal"))
+    await asyncio.sleep(0)
+    waiting = [
+        asyncio.create_task(call(name, priority))
+        for name, priority in [("bulk", "low"), ("query", "high"), ("scan", "normal")]
+    ]
+    await asyncio.sleep(0)
+    release.set()
+    await asyncio.gather(first, *waiting)
+
+    assert order == ["first", "query", "scan", "bulk"]
+    assert scheduler.active == 0
+
+
+@pytest.mark.asyncio
+async def test_cancelled_waiter_does_not_leak_slot():
+    scheduler = PriorityScheduler(1)
+    async with scheduler.slot():
+        waiter = asyncio.create_task(scheduler.slot("low").__aenter__())
+        await asyncio.sleep(0)
+        waiter.cancel()
+        with pytest.raises(asyncio.CancelledError):
+            await waiter
+    assert scheduler.active == 0
+
+    with pytest.raises(ValueError):
+        async with scheduler.slot("urgent"):
+            pass
+
+
+def test_priority_is_accepted_per_call(memory_fs):
+    from opendalfs import OpendalFileSystem
+
+    fs = OpendalFileSystem(scheme="memory", priority_slots=2, skip_instance_cache=True)
+    fs.pipe_file("prio.txt", b"data", priority="low")
+    assert fs.cat_file("prio.txt", priority="high") == b"data"
+
+    with pytest.raises(ValueError):
+        memory_fs.cat_file("prio.txt", priority="urgent")
@@ -0,0 +1,82 @@
+import pytest
+from opendal.exceptions import ConditionNotMatch, NotFound
+
+from opendalfs import CapabilityError, Sequence
+
+
+class Capability:
+    write_with_if_match = True
+    write_with_if_not_exists = True
+
+
+class Metadata:
+    def __init__(self, etag):
+        self.etag = etag
+
+
+class CounterOperator:
+    """One object with a version as ETag; ``races`` writes lose to another client"""
+
+    def __init__(self, races=0):
+        self.data = None
+        self.version = 0
+        self.races = races
+
+    def capability(self):
+        return Capability()
+
+    async def stat(self, path):
+        if self.data is None:
+            raise NotFound(path)
+        return Metadata(str(self.version))
+
+    async def read(self, path, if_match=None, **kwargs):
+        if self.data is None:
+            raise NotFound(path)
+        if if_match is not None and if_match != str(self.version):
+            raise ConditionNotMatch(path)
+        return self.data
+
+    async def write(self, path, data, if_match=None, if_not_exists=False):
+        if self.races:
+            self.races -= 1
+            self.data = str(int(self.data or b"0") + 1).encode()
+            self.version += 1
+        if if_not_exists and self.data is not None:
+            raise ConditionNotMatch(path)
+        if if_match is not None and if_match != str(self.version):
+            raise ConditionNotMatch(path)
+        self.data = data
+        self.version += 1
+
+
+def test_sequence_hands_out_increasing_values(memory_fs):
+    memory_fs.async_fs = CounterOperator()
+    seq = Sequence(memory_fs, "runs/id", start=100)
+
+    assert seq.current() == 100
+    assert [seq.next() for _ in range(3)] == [100, 101, 102]
+    assert memory_fs.async_fs.data == b"103"
+
+
+def test_sequence_retries_after_losing_a_race(memory_fs):
+    memory_fs.async_fs = CounterOperator(races=2)
+    seq = Sequence(memory_fs, "runs/id")
+
+    # Another client took 0 and 1 while this one was writing.
+    assert seq.next() == 2
+    assert memory_fs.async_fs.data == b"3"
+
+    memory_fs.async_fs = CounterOperator(races=5)
+    with pytest.raises(RuntimeError):
+        Sequence(memory_fs, "runs/id", max_attempts=3).next()
+
+
+def test_sequence_requires_conditional_writes(memory_fs):
+    class NoConditions:
+        def capability(self):
+            return object()
+
+    memory_fs.async_fs = NoConditions()
+    with pytest.raises(CapabilityError):
+        Sequence(memory_fs, "runs/id").next()
@@ -0,0 +1,46 @@
+from urllib.error import HTTPError
+from urllib.request import Request, urlopen
+
+import pytest
+
+from opendalfs.server import RangeServer, parse_range
+
+
+def test_parse_range():
+    assert parse_range(None, 10) is None
+    assert parse_range("bytes=2-4", 10) == (2, 4)
+    assert parse_range("bytes=7-", 10) == (7, 9)
+    assert parse_range("bytes=-3", 10) == (7, 9)
+    assert parse_range("bytes=5-100", 10) == (5, 9)
+    assert parse_range("bytes=0-1,4-5", 10) is None
+    with pytest.raises(ValueError):
+        parse_range("bytes=10-", 10)
+
+
+def test_range_server_serves_objects(memory_fs):
+    memory_fs.pipe_file("media/clip.bin", b"0123456789")
+
+    with RangeServer(memory_fs) as server:
+        with urlopen(f"{server.url}/media/clip.bin") as response:
+            assert response.status == 200
+            assert response.headers["Accept-Ranges"] == "bytes"
+            assert response.read() == b"0123456789"
+
+        request = Request(f"{server.url}/media/clip.bin", headers={"Range": "bytes=2-4"})
+        with urlopen(request) as response:
+            assert response.status == 206
+            assert response.headers["Content-Range"] == "bytes 2-4/10"
+            assert response.read() == b"234"
+
+        head = Request(f"{server.url}/media/clip.bin", method="HEAD")
+        with urlopen(head) as response:
+            assert response.headers["Content-Length"] == "10"
+
+        with pytest.raises(HTTPError) as excinfo:
+            urlopen(f"{server.url}/media/missing.bin")
+        assert excinfo.value.code == 404
+
+        unsatisfiable = Request(f"{server.url}/media/clip.bin", headers={"Range": "bytes=20-"})
+        with pytest.raises(HTTPError) as excinfo:
+            urlopen(unsatisfiable)
+        assert excinfo.value.code == 416
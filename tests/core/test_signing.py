@@ -0,0 +1,67 @@
+import logging
+
+import pytest
+from opendal.exceptions import PermissionDenied
+
+from opendalfs.operator import HookedOperator
+from opendalfs.signing import signing_details
+
+URL = (
+    "https://minio.internal:9000/bucket/data/a%20b.parquet"
+    "?X-Amz-Algorithm=AWS4-HMAC-SHA256"
+    "&X-Amz-Credential=AKIAEXAMPLEKEY%2F20240101%2Fus-east-1%2Fs3%2Faws4_request"
+    "&X-Amz-Date=20240101T000000Z&X-Amz-Expires=60&X-Amz-SignedHeaders=host"
+    "&X-Amz-Security-Token=sessiontoken&X-Amz-Signature=deadbeef"
+)
+
+
+def test_signing_details_rebuild_canonical_request_without_secrets():
+    details = signing_details("GET", URL, {})
+
+    assert details["algorithm"] == "AWS4-HMAC-SHA256"
+    assert details["credential"] == "AKIA**********/20240101/us-east-1/s3/aws4_request"
+    assert details["host"] == "minio.internal:9000"
+    assert details["canonical_request"].splitlines() == [
+        "GET",
+        "/bucket/data/a%20b.parquet",
+        "X-Amz-Algorithm=AWS4-HMAC-SHA256"
+        "&X-Amz-Credential=AKIA" + "%2A" * 10 + "%2F20240101%2Fus-east-1%2Fs3%2Faws4_request"
+        "&X-Amz-Date=20240101T000000Z&X-Amz-Expires=60&X-Amz-SignedHeaders=host",
+        "host:minio.internal:9000",
+        "",
+        "host",
+        "UNSIGNED-PAYLOAD",
+    ]
+    rendered = str(details)
+    assert "deadbeef" not in rendered
+    assert "sessiontoken" not in rendered
+    assert "EXAMPLEKEY" not in rendered
+
+
+@pytest.mark.asyncio
+async def test_rejected_requests_log_signing_details(memory_fs, caplog):
+    class Request:
+        method = "HEAD"
+        url = URL
+        headers = {}
+
+    class RejectingOperator:
+        async def stat(self, path):
+            raise PermissionDenied("SignatureDoesNotMatch")
+
+        def presign_stat(self, path, expiration):
+            return Request()
+
+    memory_fs.debug_signing = True
+    memory_fs.operator = HookedOperator(memory_fs, RejectingOperator(), False)
+    memory_fs.async_fs = HookedOperator(memory_fs, RejectingOperator(), True)
+
+    with caplog.at_level(logging.WARNING, logger="opendalfs"):
+        with pytest.raises(PermissionDenied):
+            await memory_fs.async_fs.stat("data/a b.parquet")
+
+    (record,) = caplog.records
+    message = record.getMessage()
+    assert message.startswith("stat data/a b.parquet was rejected")
+    assert "credential: AKIA****" in message
+    assert "deadbeef" not in message
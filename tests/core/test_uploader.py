@@ -0,0 +1,73 @@
+import threading
+
+import pytest
+
+from opendalfs import Uploader
+
+
+def test_uploader_uploads_in_background(memory_fs, tmp_path):
+    local = tmp_path / "local.bin"
+    local.write_bytes(b"from disk")
+
+    with Uploader(memory_fs, max_pending_bytes=16) as uploader:
+        for i in range(10):
+            uploader.submit(f"queue/{i}.bin", b"x" * 8)
+        uploader.submit("queue/local.bin", lpath=local)
+        report = uploader.join()
+
+    assert report.ok
+    assert len(report.uploaded) == 11
+    assert report.nbytes == 80 + len(b"from disk")
+    assert uploader.pending_bytes == 0
+    assert memory_fs.cat_file("queue/local.bin") == b"from disk"
+    assert memory_fs.cat_file("queue/9.bin") == b"x" * 8
+
+    with pytest.raises(RuntimeError):
+        uploader.submit("queue/late.bin", b"late")
+
+
+def test_uploader_bounds_pending_bytes(memory_fs):
+    release = threading.Event()
+    pipe_file = memory_fs._pipe_file
+
+    async def slow_pipe_file(path, value, **kwargs):
+        import asyncio
+
+        while not release.is_set():
+            await asyncio.sleep(0.01)
+        await pipe_file(path, value, **kwargs)
+
+    memory_fs._pipe_file = slow_pipe_file
+    uploader = Uploader(memory_fs, max_pending_bytes=10)
+    uploader.submit("bounded/a.bin", b"a" * 6)
+
+    blocked = threading.Thread(target=uploader.submit, args=("bounded/b.bin", b"b" * 6))
+    blocked.start()
+    blocked.join(0.1)
+    assert blocked.is_alive()
+    assert uploader.pending_bytes == 6
+
+    release.set()
+    blocked.join()
+    assert sorted(uploader.close().uploaded) == ["bounded/a.bin", "bounded/b.bin"]
+
+
+def test_uploader_reports_failures(memory_fs):
+    pipe_file = memory_fs._pipe_file
+
+    async def failing_pipe_file(path, value, **kwargs):
+        if path == "broken.bin":
+            raise PermissionError(path)
+        await pipe_file(path, value, **kwargs)
+
+    memory_fs._pipe_file = failing_pipe_file
+    with Uploader(memory_fs) as uploader:
+        with pytest.raises(ValueError):
+            uploader.submit("neither.bin")
+        failed = uploader.submit("broken.bin", b"data")
+        uploader.submit("ok.bin", b"fine")
+
+    report = uploader.join()
+    assert report.uploaded == ["ok.bin"]
+    assert isinstance(report.failed["broken.bin"], PermissionError)
+    assert isinstance(failed.exception(), PermissionError)